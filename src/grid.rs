@@ -1,4 +1,5 @@
 use std::fmt::{ Debug, Display };
+use crate::GridError;
 
 
 
@@ -25,6 +26,14 @@ impl<T> Grid<T> {
 		}
 	}
 
+	/// Create a new grid with some data. Returns an error if the length of the data does not match the width and height.
+	pub fn try_new(data:Vec<T>, width:usize, height:usize) -> Result<Grid<T>, GridError> {
+		if data.len() != width * height {
+			return Err(GridError::DataLengthMismatch { expected: width * height, found: data.len() });
+		}
+		Ok(Grid::new(data, width, height))
+	}
+
 	/// Create a new grid with some data from a two-dimensional array.
 	pub fn new_2d(data:Vec<Vec<T>>, width:usize, height:usize) -> Grid<T> {
 		Grid::new(data.into_iter().flatten().collect(), width, height)
@@ -97,7 +106,12 @@ impl<T> Clone for Grid<T> where T:Clone {
 }
 impl<T> PartialEq for Grid<T> where T:PartialEq {
 	fn eq(&self, other:&Self) -> bool {
-		self.data == other.data
+		self.width == other.width && self.height == other.height && self.data == other.data
+	}
+}
+impl<T> AsRef<Grid<T>> for Grid<T> {
+	fn as_ref(&self) -> &Grid<T> {
+		self
 	}
 }
 impl<T> Display for Grid<T> where T:ToString {
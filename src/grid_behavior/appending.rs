@@ -32,6 +32,40 @@ impl<T> Grid<T> where T:Clone {
 		]
 	}
 }
+impl<T> Grid<T> where T:Default + Clone {
+
+	/// Join a list of grids side by side from left to right. Grids that are not as high as the highest grid are vertically aligned using the given alignment, the remaining space is filled with default values. Returns an empty grid if the joined grids have no width or height.
+	pub fn concat_horizontal(grids:&[Grid<T>], alignment:GridAlignment) -> Grid<T> {
+		let width:usize = grids.iter().map(|grid| grid.width).sum();
+		let height:usize = grids.iter().map(|grid| grid.height).max().unwrap_or_default();
+		if width == 0 || height == 0 {
+			return Grid::new(Vec::new(), width, height);
+		}
+		let mut concatenated:Grid<T> = Grid::new(vec![T::default(); width * height], width, height);
+		let mut cursor_x:usize = 0;
+		for grid in grids {
+			concatenated.append_at(grid, [cursor_x, alignment.offset(grid.height, height)]);
+			cursor_x += grid.width;
+		}
+		concatenated
+	}
+
+	/// Join a list of grids on top of each other from top to bottom. Grids that are not as wide as the widest grid are horizontally aligned using the given alignment, the remaining space is filled with default values. Returns an empty grid if the joined grids have no width or height.
+	pub fn concat_vertical(grids:&[Grid<T>], alignment:GridAlignment) -> Grid<T> {
+		let width:usize = grids.iter().map(|grid| grid.width).max().unwrap_or_default();
+		let height:usize = grids.iter().map(|grid| grid.height).sum();
+		if width == 0 || height == 0 {
+			return Grid::new(Vec::new(), width, height);
+		}
+		let mut concatenated:Grid<T> = Grid::new(vec![T::default(); width * height], width, height);
+		let mut cursor_y:usize = 0;
+		for grid in grids {
+			concatenated.append_at(grid, [alignment.offset(grid.width, width), cursor_y]);
+			cursor_y += grid.height;
+		}
+		concatenated
+	}
+}
 impl<T> Grid<Grid<T>> where T:Default + Clone {
 
 	/// Flattens a grid of grids into one grid. Acts a lot like a CSS grid.
@@ -60,4 +94,24 @@ impl<T> Grid<Grid<T>> where T:Default + Clone {
 		// Return new grid.
 		flattened
 	}
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GridAlignment {
+	Start, // Align to the top or left side.
+	Center, // Align to the center, rounding towards the start.
+	End // Align to the bottom or right side.
+}
+impl GridAlignment {
+
+	/// Get the offset of an item of the given size within the available space.
+	fn offset(&self, size:usize, available_size:usize) -> usize {
+		match self {
+			GridAlignment::Start => 0,
+			GridAlignment::Center => (available_size - size) / 2,
+			GridAlignment::End => available_size - size
+		}
+	}
 }
\ No newline at end of file
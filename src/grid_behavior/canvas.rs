@@ -0,0 +1,127 @@
+use std::vec::IntoIter;
+use crate::{ EdgePolicy, Grid };
+
+
+
+impl<T> Grid<T> where T:Clone {
+
+	/* PADDING METHODS */
+
+	/// Grow the grid by the given amount of pixels on each side. The new pixels are filled using the given edge policy.
+	pub fn pad(&mut self, left:usize, top:usize, right:usize, bottom:usize, edge_policy:&EdgePolicy<T>) {
+		*self = self.padded(left, top, right, bottom, edge_policy);
+	}
+
+	/// Get a copy of self grown by the given amount of pixels on each side. The new pixels are filled using the given edge policy.
+	pub fn padded(&self, left:usize, top:usize, right:usize, bottom:usize, edge_policy:&EdgePolicy<T>) -> Grid<T> {
+		let width:usize = left + self.width + right;
+		let height:usize = top + self.height + bottom;
+		let mut data:Vec<T> = Vec::with_capacity(width * height);
+		for y in 0..height as isize {
+			for x in 0..width as isize {
+				let value:&T = self.get_with_edge_policy([x - left as isize, y - top as isize], edge_policy).expect("Cannot pad an empty grid without a constant edge policy.");
+				data.push(value.clone());
+			}
+		}
+		Grid::new(data, width, height)
+	}
+
+
+
+	/* TRIMMING METHODS */
+
+	/// Crop away all rows and columns at the edges of the grid of which every value matches the given predicate.
+	pub fn trim<U>(&mut self, predicate:U) where U:Fn(&T) -> bool {
+		let bounds:[usize; 4] = self.trim_bounds(predicate);
+		*self = std::mem::take(self).take(bounds);
+	}
+
+	/// Get a copy of self without the rows and columns at the edges of the grid of which every value matches the given predicate.
+	pub fn trimmed<U>(&self, predicate:U) -> Grid<T> where U:Fn(&T) -> bool {
+		self.view(self.trim_bounds(predicate)).to_grid()
+	}
+}
+impl<T> Grid<T> {
+
+	/// Get the bounds of the content that is left after trimming away all rows and columns at the edges of which every value matches the given predicate. Bounds are XYWH. If all values match, the bounds are empty.
+	pub fn trim_bounds<U>(&self, predicate:U) -> [usize; 4] where U:Fn(&T) -> bool {
+		let mut start:[usize; 2] = [self.width, self.height];
+		let mut end:[usize; 2] = [0, 0];
+		for (x, y, value) in self.pixel_iterator() {
+			if !predicate(value) {
+				start = [start[0].min(x), start[1].min(y)];
+				end = [end[0].max(x + 1), end[1].max(y + 1)];
+			}
+		}
+		if end[0] == 0 {
+			[0, 0, 0, 0]
+		} else {
+			[start[0], start[1], end[0] - start[0], end[1] - start[1]]
+		}
+	}
+
+
+
+	/* ROW AND COLUMN METHODS */
+
+	/// Insert a row of values before the row at the given Y coordinate. The row should be as long as the grid is wide, unless the grid is empty, in which case the grid becomes the row.
+	pub fn insert_row(&mut self, y:usize, row:Vec<T>) {
+		assert!(y <= self.height, "Cannot insert row at y {} in grid with a height of {}.", y, self.height);
+		if self.data.is_empty() {
+			self.width = row.len();
+			self.height = 1;
+			self.data = row;
+			return;
+		}
+		assert_eq!(row.len(), self.width, "Cannot insert row of {} values in grid with a width of {}.", row.len(), self.width);
+		let row_start:usize = y * self.width;
+		self.data.splice(row_start..row_start, row);
+		self.height += 1;
+	}
+
+	/// Remove the row at the given Y coordinate and return its values.
+	pub fn remove_row(&mut self, y:usize) -> Vec<T> {
+		assert!(y < self.height, "Cannot remove row at y {} from grid with a height of {}.", y, self.height);
+		let row_start:usize = y * self.width;
+		let row:Vec<T> = self.data.drain(row_start..row_start + self.width).collect();
+		self.height -= 1;
+		row
+	}
+
+	/// Insert a column of values before the column at the given X coordinate. The column should be as long as the grid is high, unless the grid is empty, in which case the grid becomes the column.
+	pub fn insert_column(&mut self, x:usize, column:Vec<T>) {
+		assert!(x <= self.width, "Cannot insert column at x {} in grid with a width of {}.", x, self.width);
+		if self.data.is_empty() {
+			self.width = 1;
+			self.height = column.len();
+			self.data = column;
+			return;
+		}
+		assert_eq!(column.len(), self.height, "Cannot insert column of {} values in grid with a height of {}.", column.len(), self.height);
+		let mut source_data:IntoIter<T> = std::mem::take(&mut self.data).into_iter();
+		let mut data:Vec<T> = Vec::with_capacity((self.width + 1) * self.height);
+		for value in column {
+			data.extend(source_data.by_ref().take(x));
+			data.push(value);
+			data.extend(source_data.by_ref().take(self.width - x));
+		}
+		self.data = data;
+		self.width += 1;
+	}
+
+	/// Remove the column at the given X coordinate and return its values.
+	pub fn remove_column(&mut self, x:usize) -> Vec<T> {
+		assert!(x < self.width, "Cannot remove column at x {} from grid with a width of {}.", x, self.width);
+		let mut source_data:IntoIter<T> = std::mem::take(&mut self.data).into_iter();
+		let mut data:Vec<T> = Vec::with_capacity((self.width - 1) * self.height);
+		let mut column:Vec<T> = Vec::with_capacity(self.height);
+		for _y in 0..self.height {
+			data.extend(source_data.by_ref().take(x));
+			column.extend(source_data.next());
+			data.extend(source_data.by_ref().take(self.width - x - 1));
+		}
+		self.data = data;
+		self.width -= 1;
+		column
+	}
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ EdgePolicy, Grid };
+
+
+
+	#[test]
+	fn test_pad_constant() {
+		let mut grid:Grid<usize> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		grid.pad(1, 0, 2, 1, &EdgePolicy::Constant(0));
+		println!("[padded grid]\n{grid}\n");
+
+		assert_eq!([grid.width, grid.height], [5, 3]);
+		assert_eq!(grid.data_2d(), [[0, 1, 2, 0, 0], [0, 3, 4, 0, 0], [0, 0, 0, 0, 0]]);
+	}
+
+	#[test]
+	fn test_pad_replicate_and_mirror() {
+		let grid:Grid<usize> = Grid::new(vec![1, 2, 3, 4, 5, 6], 3, 2);
+		let replicated:Grid<usize> = grid.padded(2, 1, 1, 0, &EdgePolicy::Clamp);
+		println!("[replicated]\n{replicated}\n");
+		let mirrored:Grid<usize> = grid.padded(2, 1, 1, 0, &EdgePolicy::Mirror);
+		println!("[mirrored]\n{mirrored}\n");
+
+		assert_eq!(replicated.data_2d(), [[1, 1, 1, 2, 3, 3], [1, 1, 1, 2, 3, 3], [4, 4, 4, 5, 6, 6]]);
+		assert_eq!(mirrored.data_2d(), [[6, 5, 4, 5, 6, 5], [3, 2, 1, 2, 3, 2], [6, 5, 4, 5, 6, 5]]);
+	}
+
+	#[test]
+	fn test_trim() {
+		let mut grid:Grid<usize> = Grid::new(vec![
+			0, 0, 0, 0,
+			0, 1, 0, 0,
+			0, 0, 2, 0,
+			0, 0, 0, 0
+		], 4, 4);
+		assert_eq!(grid.trim_bounds(|value| *value == 0), [1, 1, 2, 2]);
+		grid.trim(|value| *value == 0);
+		println!("[trimmed grid]\n{grid}\n");
+
+		assert_eq!([grid.width, grid.height], [2, 2]);
+		assert_eq!(grid.data, vec![1, 0, 0, 2]);
+		assert!(grid.trimmed(|_| true).is_empty());
+		assert_eq!(grid.trimmed(|value| *value != 1).data, vec![1]);
+	}
+
+	#[test]
+	fn test_insert_and_remove_rows() {
+		let mut grid:Grid<usize> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		grid.insert_row(1, vec![8, 9]);
+		grid.insert_row(3, vec![6, 7]);
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!([grid.width, grid.height], [2, 4]);
+		assert_eq!(grid.data_2d(), [[1, 2], [8, 9], [3, 4], [6, 7]]);
+		assert_eq!(grid.remove_row(0), vec![1, 2]);
+		assert_eq!(grid.data_2d(), [[8, 9], [3, 4], [6, 7]]);
+
+		let mut empty_grid:Grid<usize> = Grid::empty();
+		empty_grid.insert_row(0, vec![1, 2, 3]);
+		assert_eq!([empty_grid.width, empty_grid.height], [3, 1]);
+
+		let mut narrow_grid:Grid<usize> = Grid::new(Vec::new(), 0, 3);
+		narrow_grid.insert_row(2, vec![1, 2]);
+		assert_eq!([narrow_grid.width, narrow_grid.height], [2, 1]);
+		assert_eq!(narrow_grid.data_2d(), [[1, 2]]);
+	}
+
+	#[test]
+	fn test_insert_and_remove_columns() {
+		let mut grid:Grid<usize> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		grid.insert_column(0, vec![8, 9]);
+		grid.insert_column(3, vec![6, 7]);
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!([grid.width, grid.height], [4, 2]);
+		assert_eq!(grid.data_2d(), [[8, 1, 2, 6], [9, 3, 4, 7]]);
+		assert_eq!(grid.remove_column(2), vec![2, 4]);
+		assert_eq!(grid.data_2d(), [[8, 1, 6], [9, 3, 7]]);
+
+		let mut empty_grid:Grid<usize> = Grid::empty();
+		empty_grid.insert_column(0, vec![1, 2]);
+		assert_eq!([empty_grid.width, empty_grid.height], [1, 2]);
+		assert_eq!(empty_grid.data, vec![1, 2]);
+
+		let mut flat_grid:Grid<usize> = Grid::new(Vec::new(), 3, 0);
+		flat_grid.insert_column(1, vec![1, 2]);
+		assert_eq!([flat_grid.width, flat_grid.height], [1, 2]);
+		assert_eq!(flat_grid.data_2d(), [[1], [2]]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_insert_row_size_mismatch() {
+		let mut grid:Grid<usize> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		grid.insert_row(0, vec![1, 2, 3]);
+	}
+}
\ No newline at end of file
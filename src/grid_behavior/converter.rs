@@ -1,4 +1,4 @@
-use crate::Grid;
+use crate::{ Grid, GridError };
 
 
 
@@ -25,4 +25,16 @@ impl<T> Grid<T> {
 			height
 		}
 	}
+
+	/// Combine the grid with another grid of the same size pixel by pixel into a grid of a new type. Returns an error if the grids are not the same size.
+	pub fn zip_map<U, V, W>(&self, other:&Grid<U>, mut zip_function:W) -> Result<Grid<V>, GridError> where W:FnMut(&T, &U) -> V {
+		if [self.width, self.height] != [other.width, other.height] {
+			return Err(GridError::SizeMismatch { expected: [self.width, self.height], found: [other.width, other.height] });
+		}
+		Ok(Grid {
+			data: self.iter().zip(other.iter()).map(|(left, right)| zip_function(left, right)).collect(),
+			width: self.width,
+			height: self.height
+		})
+	}
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-	use crate::Grid;
+	use crate::{ Grid, GridError };
 
 
 	#[test]
@@ -15,4 +15,16 @@ mod tests {
 		assert_eq!(float_grid.data(), &[0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5]);
 		assert_eq!(int_grid, float_grid.map(|float| (float * 2.0) as i32));
 	}
+
+	#[test]
+	fn test_zip_map() {
+		let int_grid:Grid<i32> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		let mask_grid:Grid<bool> = Grid::new(vec![true, false, false, true], 2, 2);
+		let zipped:Grid<f32> = int_grid.zip_map(&mask_grid, |value, enabled| if *enabled { *value as f32 * 0.5 } else { 0.0 }).unwrap();
+		println!("[zipped]\n{zipped}\n");
+
+		assert_eq!(zipped.data, vec![0.5, 0.0, 0.0, 2.0]);
+		let other_size:Grid<bool> = Grid::new(vec![true; 2], 1, 2);
+		assert!(matches!(int_grid.zip_map(&other_size, |value, _| *value), Err(GridError::SizeMismatch { expected: [2, 2], found: [1, 2] })));
+	}
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use crate::{ Grid, GridIndexer };
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum EdgePolicy<T> {
+	Clamp, // Repeat the value of the nearest edge.
+	Wrap, // Treat the grid as a torus, continuing at the opposite edge.
+	Mirror, // Reflect the grid at its edges, without repeating the edge itself.
+	Constant(T) // Use a fixed value for all positions outside of the grid.
+}
+impl<T> EdgePolicy<T> {
+
+	/// Resolve a signed coordinate to a coordinate inside a grid of the given size. Returns None if the coordinate should use the constant value, or if the grid is empty.
+	pub fn resolve_xy(&self, x:isize, y:isize, width:usize, height:usize) -> Option<[usize; 2]> {
+		if x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height {
+			return Some([x as usize, y as usize]);
+		}
+		if width == 0 || height == 0 {
+			return None;
+		}
+		match self {
+			EdgePolicy::Clamp => Some([Self::clamp_axis(x, width), Self::clamp_axis(y, height)]),
+			EdgePolicy::Wrap => Some([Self::wrap_axis(x, width), Self::wrap_axis(y, height)]),
+			EdgePolicy::Mirror => Some([Self::mirror_axis(x, width), Self::mirror_axis(y, height)]),
+			EdgePolicy::Constant(_) => None
+		}
+	}
+
+	/// Clamp a position on an axis to the given size.
+	fn clamp_axis(position:isize, size:usize) -> usize {
+		position.clamp(0, size as isize - 1) as usize
+	}
+
+	/// Wrap a position on an axis around the given size.
+	fn wrap_axis(position:isize, size:usize) -> usize {
+		position.rem_euclid(size as isize) as usize
+	}
+
+	/// Mirror a position on an axis back into the given size.
+	fn mirror_axis(position:isize, size:usize) -> usize {
+		if size == 1 {
+			return 0;
+		}
+		let period:isize = 2 * size as isize - 2;
+		let position:isize = position.rem_euclid(period);
+		(if position < size as isize { position } else { period - position }) as usize
+	}
+}
+
+
+
+impl<T> Grid<T> {
+
+	/// Get a reference to the value at the given position. Positions outside of the grid are resolved using the given edge policy. Only returns None if the grid is empty and the policy has no constant value.
+	pub fn get_with_edge_policy<'a, U>(&'a self, indexer:U, edge_policy:&'a EdgePolicy<T>) -> Option<&'a T> where U:GridIndexer {
+		let (x, y) = indexer.to_signed_grid_xy(self);
+		match edge_policy.resolve_xy(x, y, self.width, self.height) {
+			Some([x, y]) => Some(&self.data[y * self.width + x]),
+			None => match edge_policy {
+				EdgePolicy::Constant(value) => Some(value),
+				_ => None
+			}
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ EdgePolicy, Grid };
+
+
+
+	fn sample_grid() -> Grid<i32> {
+		Grid::new((0..9).collect(), 3, 3)
+	}
+
+	fn row_with_policy(grid:&Grid<i32>, y:isize, edge_policy:&EdgePolicy<i32>) -> Vec<i32> {
+		(-4..7).map(|x| *grid.get_with_edge_policy([x, y as i32], edge_policy).unwrap()).collect()
+	}
+
+
+
+	#[test]
+	fn test_edge_policy_inside() {
+		let grid:Grid<i32> = sample_grid();
+		for edge_policy in [EdgePolicy::Clamp, EdgePolicy::Wrap, EdgePolicy::Mirror, EdgePolicy::Constant(-1)] {
+			for (x, y, value) in grid.pixel_iterator() {
+				assert_eq!(grid.get_with_edge_policy((x as isize, y as isize), &edge_policy), Some(value));
+			}
+		}
+	}
+
+	#[test]
+	fn test_edge_policy_clamp() {
+		let grid:Grid<i32> = sample_grid();
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(row_with_policy(&grid, 0, &EdgePolicy::Clamp), vec![0, 0, 0, 0, 0, 1, 2, 2, 2, 2, 2]);
+		assert_eq!(row_with_policy(&grid, -5, &EdgePolicy::Clamp), vec![0, 0, 0, 0, 0, 1, 2, 2, 2, 2, 2]);
+		assert_eq!(row_with_policy(&grid, 9, &EdgePolicy::Clamp), vec![6, 6, 6, 6, 6, 7, 8, 8, 8, 8, 8]);
+	}
+
+	#[test]
+	fn test_edge_policy_wrap() {
+		let grid:Grid<i32> = sample_grid();
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(row_with_policy(&grid, 1, &EdgePolicy::Wrap), vec![5, 3, 4, 5, 3, 4, 5, 3, 4, 5, 3]);
+		assert_eq!(row_with_policy(&grid, -1, &EdgePolicy::Wrap), vec![8, 6, 7, 8, 6, 7, 8, 6, 7, 8, 6]);
+	}
+
+	#[test]
+	fn test_edge_policy_mirror() {
+		let grid:Grid<i32> = sample_grid();
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(row_with_policy(&grid, 0, &EdgePolicy::Mirror), vec![0, 1, 2, 1, 0, 1, 2, 1, 0, 1, 2]);
+		assert_eq!(row_with_policy(&grid, -1, &EdgePolicy::Mirror), vec![3, 4, 5, 4, 3, 4, 5, 4, 3, 4, 5]);
+		assert_eq!(*Grid::new(vec![7], 1, 1).get_with_edge_policy([-3, 5], &EdgePolicy::Mirror).unwrap(), 7);
+	}
+
+	#[test]
+	fn test_edge_policy_constant() {
+		let grid:Grid<i32> = sample_grid();
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(row_with_policy(&grid, 2, &EdgePolicy::Constant(-1)), vec![-1, -1, -1, -1, 6, 7, 8, -1, -1, -1, -1]);
+		assert_eq!(row_with_policy(&grid, 3, &EdgePolicy::Constant(-1)), vec![-1; 11]);
+	}
+
+	#[test]
+	fn test_edge_policy_empty_grid() {
+		let grid:Grid<i32> = Grid::empty();
+
+		assert_eq!(grid.get_with_edge_policy([0, 0], &EdgePolicy::Clamp), None);
+		assert_eq!(grid.get_with_edge_policy([0, 0], &EdgePolicy::Constant(3)), Some(&3));
+	}
+}
\ No newline at end of file
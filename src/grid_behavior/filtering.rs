@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+use crate::{ EdgePolicy, Grid, GridNumeric };
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Kernel {
+	weights:Grid<f64>,
+	anchor:[usize; 2],
+	factors:Option<(Vec<f64>, Vec<f64>)> // Horizontal and vertical factors of separable kernels.
+}
+impl Kernel {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new kernel with its anchor in the center.
+	pub fn new(weights:Grid<f64>) -> Kernel {
+		let anchor:[usize; 2] = [weights.width / 2, weights.height / 2];
+		Kernel::with_anchor(weights, anchor)
+	}
+
+	/// Create a new kernel with the given anchor. Panics if the anchor is not inside the weights grid.
+	pub fn with_anchor(weights:Grid<f64>, anchor:[usize; 2]) -> Kernel {
+		assert!(anchor[0] < weights.width && anchor[1] < weights.height, "Kernel anchor {:?} falls outside of kernel of {}x{}.", anchor, weights.width, weights.height);
+		Kernel { weights, anchor, factors: None }
+	}
+
+	/// Create a separable kernel from its horizontal and vertical factors. Convolving with a separable kernel is done in two one-dimensional passes. Panics if either of the factors is empty.
+	pub fn separable(horizontal:Vec<f64>, vertical:Vec<f64>) -> Kernel {
+		assert!(!horizontal.is_empty() && !vertical.is_empty(), "Separable kernel factors cannot be empty.");
+		let weights:Grid<f64> = Grid::new(vertical.iter().flat_map(|vertical_weight| horizontal.iter().map(move |horizontal_weight| vertical_weight * horizontal_weight)).collect(), horizontal.len(), vertical.len());
+		Kernel {
+			anchor: [weights.width / 2, weights.height / 2],
+			weights,
+			factors: Some((horizontal, vertical))
+		}
+	}
+
+	/// Create a kernel that averages all values within the given radius.
+	pub fn box_blur(radius:usize) -> Kernel {
+		let size:usize = radius * 2 + 1;
+		Kernel::separable(vec![1.0 / size as f64; size], vec![1.0 / size as f64; size])
+	}
+
+	/// Create a gaussian blur kernel with the given standard deviation. The kernel reaches three standard deviations in each direction.
+	pub fn gaussian(sigma:f64) -> Kernel {
+		if sigma <= 0.0 {
+			return Kernel::separable(vec![1.0], vec![1.0]);
+		}
+		let radius:isize = (sigma * 3.0).ceil() as isize;
+		let weights:Vec<f64> = (-radius..=radius).map(|offset| (-((offset * offset) as f64) / (2.0 * sigma * sigma)).exp()).collect();
+		let weights_sum:f64 = weights.iter().sum();
+		let weights:Vec<f64> = weights.into_iter().map(|weight| weight / weights_sum).collect();
+		Kernel::separable(weights.clone(), weights)
+	}
+
+	/// Create a kernel that sharpens by subtracting the direct neighbors.
+	pub fn sharpen() -> Kernel {
+		Kernel::new(Grid::new(vec![0.0, -1.0, 0.0, -1.0, 5.0, -1.0, 0.0, -1.0, 0.0], 3, 3))
+	}
+
+	/// Create a Sobel kernel for the horizontal gradient. Results are positive where values increase to the right.
+	pub fn sobel_x() -> Kernel {
+		Kernel::separable(vec![-1.0, 0.0, 1.0], vec![1.0, 2.0, 1.0])
+	}
+
+	/// Create a Sobel kernel for the vertical gradient. Results are positive where values increase downwards.
+	pub fn sobel_y() -> Kernel {
+		Kernel::separable(vec![1.0, 2.0, 1.0], vec![-1.0, 0.0, 1.0])
+	}
+
+	/// Create a Scharr kernel for the horizontal gradient. Results are positive where values increase to the right.
+	pub fn scharr_x() -> Kernel {
+		Kernel::separable(vec![-1.0, 0.0, 1.0], vec![3.0, 10.0, 3.0])
+	}
+
+	/// Create a Scharr kernel for the vertical gradient. Results are positive where values increase downwards.
+	pub fn scharr_y() -> Kernel {
+		Kernel::separable(vec![3.0, 10.0, 3.0], vec![-1.0, 0.0, 1.0])
+	}
+
+	/// Create a Laplacian kernel of the direct neighbors.
+	pub fn laplacian() -> Kernel {
+		Kernel::new(Grid::new(vec![0.0, 1.0, 0.0, 1.0, -4.0, 1.0, 0.0, 1.0, 0.0], 3, 3))
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the weights of the kernel.
+	pub fn weights(&self) -> &Grid<f64> {
+		&self.weights
+	}
+
+	/// Get the position in the kernel that is placed over the pixel being calculated.
+	pub fn anchor(&self) -> [usize; 2] {
+		self.anchor
+	}
+
+	/// Whether or not the kernel can be applied in two one-dimensional passes.
+	pub fn is_separable(&self) -> bool {
+		self.factors.is_some()
+	}
+}
+
+
+
+impl<T> Grid<T> where T:GridNumeric {
+
+	/* HELPER METHODS */
+
+	/// Get the value at the given signed position as float. Positions outside of the grid are resolved using the edge policy.
+	fn sample_with_edge_policy(&self, x:isize, y:isize, edge_policy:&EdgePolicy<T>) -> f64 {
+		match edge_policy.resolve_xy(x, y, self.width, self.height) {
+			Some([x, y]) => self.data[y * self.width + x].to_f64(),
+			None => match edge_policy {
+				EdgePolicy::Constant(value) => value.to_f64(),
+				_ => 0.0
+			}
+		}
+	}
+
+	/// Convolve the grid with a full kernel.
+	fn convolve_full(&self, kernel:&Kernel, edge_policy:&EdgePolicy<T>) -> Grid<f64> {
+		let weights:Vec<(isize, isize, f64)> = kernel.weights.data.iter().enumerate().filter(|(_, weight)| **weight != 0.0).map(|(index, weight)| (
+			(index % kernel.weights.width) as isize - kernel.anchor[0] as isize,
+			(index / kernel.weights.width) as isize - kernel.anchor[1] as isize,
+			*weight
+		)).collect();
+		let mut data:Vec<f64> = Vec::with_capacity(self.data.len());
+		for y in 0..self.height as isize {
+			for x in 0..self.width as isize {
+				data.push(weights.iter().map(|(offset_x, offset_y, weight)| self.sample_with_edge_policy(x + offset_x, y + offset_y, edge_policy) * weight).sum());
+			}
+		}
+		Grid::new(data, self.width, self.height)
+	}
+
+	/// Convolve the grid with a separable kernel in a horizontal and a vertical pass.
+	fn convolve_separable(&self, horizontal:&[f64], vertical:&[f64], anchor:[usize; 2], edge_policy:&EdgePolicy<T>) -> Grid<f64> {
+
+		// Horizontal pass.
+		let mut horizontal_pass:Vec<f64> = Vec::with_capacity(self.data.len());
+		for y in 0..self.height as isize {
+			for x in 0..self.width as isize {
+				horizontal_pass.push(horizontal.iter().enumerate().map(|(offset, weight)| self.sample_with_edge_policy(x + offset as isize - anchor[0] as isize, y, edge_policy) * weight).sum());
+			}
+		}
+
+		// Vertical pass. Rows outside of the grid hold the horizontally convolved constant value.
+		let constant_row_value:f64 = match edge_policy {
+			EdgePolicy::Constant(value) => value.to_f64() * horizontal.iter().sum::<f64>(),
+			_ => 0.0
+		};
+		let mut data:Vec<f64> = Vec::with_capacity(self.data.len());
+		for y in 0..self.height as isize {
+			for x in 0..self.width {
+				data.push(vertical.iter().enumerate().map(|(offset, weight)| {
+					match edge_policy.resolve_xy(x as isize, y + offset as isize - anchor[1] as isize, self.width, self.height) {
+						Some([_, source_y]) => horizontal_pass[source_y * self.width + x] * weight,
+						None => constant_row_value * weight
+					}
+				}).sum());
+			}
+		}
+		Grid::new(data, self.width, self.height)
+	}
+
+
+
+	/* CONVOLUTION METHODS */
+
+	/// Convolve the grid with the given kernel. The kernel is applied without flipping it. Positions outside of the grid are resolved using the given edge policy. The result is converted to the requested numeric type, integer types round and clamp the results.
+	pub fn convolve<U>(&self, kernel:&Kernel, edge_policy:&EdgePolicy<T>) -> Grid<U> where U:GridNumeric {
+		let result:Grid<f64> = match &kernel.factors {
+			Some((horizontal, vertical)) => self.convolve_separable(horizontal, vertical, kernel.anchor, edge_policy),
+			None => self.convolve_full(kernel, edge_policy)
+		};
+		result.map(U::from_f64)
+	}
+
+	/// Create a copy of the grid where each value is the average of the values within the given radius.
+	pub fn box_blur(&self, radius:usize, edge_policy:&EdgePolicy<T>) -> Grid<T> {
+		self.convolve(&Kernel::box_blur(radius), edge_policy)
+	}
+
+	/// Create a copy of the grid blurred with a gaussian kernel of the given standard deviation.
+	pub fn gaussian_blur(&self, sigma:f64, edge_policy:&EdgePolicy<T>) -> Grid<T> {
+		self.convolve(&Kernel::gaussian(sigma), edge_policy)
+	}
+
+	/// Create a sharpened copy of the grid.
+	pub fn sharpen(&self, edge_policy:&EdgePolicy<T>) -> Grid<T> {
+		self.convolve(&Kernel::sharpen(), edge_policy)
+	}
+
+	/// Get the horizontal and vertical gradients of the grid using Sobel kernels.
+	pub fn sobel_gradients(&self, edge_policy:&EdgePolicy<T>) -> [Grid<f64>; 2] {
+		[self.convolve(&Kernel::sobel_x(), edge_policy), self.convolve(&Kernel::sobel_y(), edge_policy)]
+	}
+
+	/// Get the gradient magnitude of the grid using Sobel kernels.
+	pub fn sobel_magnitude(&self, edge_policy:&EdgePolicy<T>) -> Grid<f64> {
+		let [gradient_x, gradient_y] = self.sobel_gradients(edge_policy);
+		gradient_x.zip_map(&gradient_y, |x, y| x.hypot(*y)).unwrap()
+	}
+
+	/// Get the horizontal and vertical gradients of the grid using Scharr kernels.
+	pub fn scharr_gradients(&self, edge_policy:&EdgePolicy<T>) -> [Grid<f64>; 2] {
+		[self.convolve(&Kernel::scharr_x(), edge_policy), self.convolve(&Kernel::scharr_y(), edge_policy)]
+	}
+
+	/// Get the gradient magnitude of the grid using Scharr kernels.
+	pub fn scharr_magnitude(&self, edge_policy:&EdgePolicy<T>) -> Grid<f64> {
+		let [gradient_x, gradient_y] = self.scharr_gradients(edge_policy);
+		gradient_x.zip_map(&gradient_y, |x, y| x.hypot(*y)).unwrap()
+	}
+
+	/// Get the Laplacian of the grid.
+	pub fn laplacian(&self, edge_policy:&EdgePolicy<T>) -> Grid<f64> {
+		self.convolve(&Kernel::laplacian(), edge_policy)
+	}
+
+
+
+	/* RANK FILTER METHODS */
+
+	/// Create a copy of the grid where each value is the median of the square of values within the given radius.
+	pub fn median_filter(&self, radius:usize, edge_policy:&EdgePolicy<T>) -> Grid<T> {
+		let radius:isize = radius as isize;
+		let mut window:Vec<T> = Vec::with_capacity(((radius * 2 + 1) * (radius * 2 + 1)) as usize);
+		let mut data:Vec<T> = Vec::with_capacity(self.data.len());
+		for y in 0..self.height as isize {
+			for x in 0..self.width as isize {
+				window.clear();
+				for offset_y in -radius..=radius {
+					for offset_x in -radius..=radius {
+						if let Some(value) = self.get_with_edge_policy([x + offset_x, y + offset_y], edge_policy) {
+							window.push(*value);
+						}
+					}
+				}
+				let center:usize = window.len() / 2;
+				window.select_nth_unstable_by(center, |left, right| left.partial_cmp(right).unwrap_or(Ordering::Equal));
+				data.push(window[center]);
+			}
+		}
+		Grid::new(data, self.width, self.height)
+	}
+}
\ No newline at end of file
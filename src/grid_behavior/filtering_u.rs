@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ EdgePolicy, Grid, Kernel };
+
+
+
+	fn assert_grids_close(left:&Grid<f64>, right:&Grid<f64>) {
+		assert_eq!([left.width, left.height], [right.width, right.height]);
+		for (left, right) in left.data.iter().zip(&right.data) {
+			assert!((left - right).abs() < 1e-9, "{left} != {right}");
+		}
+	}
+
+
+
+	#[test]
+	fn test_convolve_full_kernel() {
+		let grid:Grid<i32> = Grid::new((1..=9).collect(), 3, 3);
+		let kernel:Kernel = Kernel::new(Grid::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, -1.0, 0.0, 0.0, 0.0], 3, 3));
+		let result:Grid<i32> = grid.convolve(&kernel, &EdgePolicy::Constant(0));
+		println!("[result]\n{result}\n");
+
+		assert_eq!(result.data, vec![-2, -2, 2, -5, -2, 5, -8, -2, 8]);
+		let clamped:Grid<i32> = grid.convolve(&kernel, &EdgePolicy::Clamp);
+		assert_eq!(clamped.data, vec![-1, -2, -1, -1, -2, -1, -1, -2, -1]);
+	}
+
+	#[test]
+	fn test_convolve_anchor() {
+		let grid:Grid<u8> = Grid::new((1..=4).collect(), 2, 2);
+		let shift:Kernel = Kernel::with_anchor(Grid::new(vec![0.0, 1.0], 2, 1), [0, 0]);
+
+		assert_eq!(grid.convolve::<u8>(&shift, &EdgePolicy::Constant(0)).data, vec![2, 0, 4, 0]);
+		assert_eq!(grid.convolve::<u8>(&shift, &EdgePolicy::Wrap).data, vec![2, 1, 4, 3]);
+	}
+
+	#[test]
+	fn test_separable_matches_full() {
+		let grid:Grid<f64> = Grid::new((0..30).map(|value| ((value * 7) % 11) as f64).collect(), 6, 5);
+		for kernel in [Kernel::gaussian(1.0), Kernel::sobel_x(), Kernel::scharr_y(), Kernel::box_blur(2)] {
+			assert!(kernel.is_separable());
+			let full_kernel:Kernel = Kernel::new(kernel.weights().clone());
+			assert!(!full_kernel.is_separable());
+			for edge_policy in [EdgePolicy::Clamp, EdgePolicy::Wrap, EdgePolicy::Mirror, EdgePolicy::Constant(3.0)] {
+				assert_grids_close(&grid.convolve(&kernel, &edge_policy), &grid.convolve(&full_kernel, &edge_policy));
+			}
+		}
+	}
+
+	#[test]
+	fn test_blurs() {
+		let mut grid:Grid<f32> = Grid::new(vec![0.0; 25], 5, 5);
+		grid[[2, 2]] = 9.0;
+		let blurred:Grid<f32> = grid.box_blur(1, &EdgePolicy::Constant(0.0));
+		println!("[blurred]\n{blurred}\n");
+
+		assert_eq!(blurred.data_2d()[1], [0.0, 1.0, 1.0, 1.0, 0.0]);
+		assert_eq!(blurred.sum(), 9.0);
+		let gaussian:Grid<f32> = grid.gaussian_blur(1.0, &EdgePolicy::Clamp);
+		assert!(gaussian[[2, 2]] < 9.0 && gaussian[[2, 2]] > gaussian[[1, 2]] && gaussian[[1, 2]] > gaussian[[0, 2]]);
+		assert!((Kernel::gaussian(2.0).weights().sum() - 1.0).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_sharpen_and_laplacian() {
+		let grid:Grid<u8> = Grid::new(vec![10, 10, 10, 10, 20, 10, 10, 10, 10], 3, 3);
+
+		assert_eq!(grid.sharpen(&EdgePolicy::Clamp).data, vec![10, 0, 10, 0, 60, 0, 10, 0, 10]);
+		assert_eq!(grid.laplacian(&EdgePolicy::Clamp).data, vec![0.0, 10.0, 0.0, 10.0, -40.0, 10.0, 0.0, 10.0, 0.0]);
+	}
+
+	#[test]
+	fn test_gradients() {
+		let grid:Grid<u8> = Grid::new(vec![0, 0, 10, 10, 0, 0, 10, 10, 0, 0, 10, 10], 4, 3);
+		let [gradient_x, gradient_y] = grid.sobel_gradients(&EdgePolicy::Clamp);
+		println!("[gradient x]\n{gradient_x}\n");
+
+		assert_eq!(gradient_x.data_2d()[1], [0.0, 40.0, 40.0, 0.0]);
+		assert!(gradient_y.data.iter().all(|value| *value == 0.0));
+		assert_eq!(grid.sobel_magnitude(&EdgePolicy::Clamp), gradient_x);
+		assert_eq!(grid.scharr_gradients(&EdgePolicy::Clamp)[0].data_2d()[1], [0.0, 160.0, 160.0, 0.0]);
+	}
+
+	#[test]
+	fn test_median_filter() {
+		let grid:Grid<u8> = Grid::new(vec![
+			1, 1, 1, 1,
+			1, 9, 1, 1,
+			1, 1, 5, 5,
+			1, 1, 5, 5
+		], 4, 4);
+		let filtered:Grid<u8> = grid.median_filter(1, &EdgePolicy::Clamp);
+		println!("[filtered]\n{filtered}\n");
+
+		assert_eq!(filtered.data_2d(), [[1, 1, 1, 1], [1, 1, 1, 1], [1, 1, 5, 5], [1, 1, 5, 5]]);
+	}
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::ops::{ Index, IndexMut, Range };
-use crate::Grid;
+use crate::{ Grid, GridError, Neighborhood };
 
 
 
@@ -21,6 +21,16 @@ impl<T> Grid<T> {
 		index < self.len()
 	}
 
+	/// Get a reference to the value at the given position. Returns None if the position falls outside of the grid.
+	pub fn get<U>(&self, indexer:U) -> Option<&T> where U:GridIndexer {
+		indexer.try_to_grid_index(self).map(|index| &self.data[index])
+	}
+
+	/// Get a mutable reference to the value at the given position. Returns None if the position falls outside of the grid.
+	pub fn get_mut<U>(&mut self, indexer:U) -> Option<&mut T> where U:GridIndexer {
+		indexer.try_to_grid_index(self).map(|index| &mut self.data[index])
+	}
+
 	/// Wether or not the given and X and Y coordinate are valid in the grid.
 	pub fn xy_is_valid(&self, x:usize, y:usize) -> bool {
 		x < self.width && y < self.height
@@ -28,19 +38,7 @@ impl<T> Grid<T> {
 
 	/// Get the available neighbors for a specific index.
 	pub fn index_neighbors<U>(&self, index:U) -> Vec<usize> where U:GridIndexer {
-		let max_x:usize = self.width - 1;
-		let max_y:usize = self.len() - self.width;
-		self._index_neighbors(index.to_grid_index(self), max_x, max_y)
-	}
-
-	/// A version of index_neighbors that takes arguments for repetitive calculations.
-	pub(crate) fn _index_neighbors(&self, position_index:usize, max_x:usize, max_y:usize) -> Vec<usize> {
-		[
-			if position_index % self.width > 0 { Some(position_index - 1) } else { None }, // Left
-			if position_index > self.width { Some(position_index - self.width) } else { None }, // Top
-			if position_index % self.width != max_x { Some(position_index + 1) } else { None }, // Right
-			if position_index < max_y { Some(position_index + self.width) } else { None } // Bottom
-		].into_iter().flatten().collect()
+		self.index_neighbors_in(index, &Neighborhood::four_connected())
 	}
 }
 impl<T, U> Index<U> for Grid<T> where U:GridIndexer {
@@ -82,19 +80,78 @@ pub trait GridIndexer {
 		let index:usize = self.to_grid_index(grid);
 		(index % grid.width, index / grid.width)
 	}
+
+	/// Convert the index to a signed X and Y coordinate on the grid. Unlike the other conversions, the coordinate is allowed to fall outside of the grid.
+	fn to_signed_grid_xy<T>(&self, grid:&Grid<T>) -> (isize, isize) {
+		let (x, y) = self.to_grid_xy(grid);
+		(x as isize, y as isize)
+	}
+
+	/// Try to convert the indexer to an actual index. Returns None if the position falls outside of the grid.
+	fn try_to_grid_index<T>(&self, grid:&Grid<T>) -> Option<usize> {
+		let (x, y) = self.to_signed_grid_xy(grid);
+		if x >= 0 && y >= 0 && (x as usize) < grid.width && (y as usize) < grid.height {
+			Some(y as usize * grid.width + x as usize)
+		} else {
+			None
+		}
+	}
+
+	/// Try to convert the indexer to an actual index. Returns an error if the position falls outside of the grid.
+	fn to_checked_grid_index<T>(&self, grid:&Grid<T>) -> Result<usize, GridError> {
+		self.try_to_grid_index(grid).ok_or_else(|| {
+			let (x, y) = self.to_signed_grid_xy(grid);
+			GridError::OutOfBounds { position: [x, y], size: [grid.width, grid.height] }
+		})
+	}
 }
 impl GridIndexer for usize {
 	fn to_grid_index<T>(&self, _grid:&Grid<T>) -> usize {
 		*self
 	}
+	fn try_to_grid_index<T>(&self, grid:&Grid<T>) -> Option<usize> {
+		if *self < grid.len() { Some(*self) } else { None }
+	}
 }
 impl GridIndexer for [usize; 2] {
 	fn to_grid_index<T>(&self, grid:&Grid<T>) -> usize {
 		self[1] * grid.width + self[0]
 	}
+	fn to_signed_grid_xy<T>(&self, _grid:&Grid<T>) -> (isize, isize) {
+		(self[0] as isize, self[1] as isize)
+	}
 }
 impl GridIndexer for (usize, usize) {
 	fn to_grid_index<T>(&self, grid:&Grid<T>) -> usize {
 		self.1 * grid.width + self.0
 	}
-}
\ No newline at end of file
+	fn to_signed_grid_xy<T>(&self, _grid:&Grid<T>) -> (isize, isize) {
+		(self.0 as isize, self.1 as isize)
+	}
+}
+
+
+
+/* SIGNED IMPLEMENTATIONS */
+
+macro_rules! implement_signed_indexer {
+	($type:ty, |$indexer:ident| $xy:expr) => {
+		impl GridIndexer for $type {
+			fn to_grid_index<T>(&self, grid:&Grid<T>) -> usize {
+				match self.try_to_grid_index(grid) {
+					Some(index) => index,
+					None => panic!("Position {:?} falls outside of the {}x{} grid.", self.to_signed_grid_xy(grid), grid.width, grid.height)
+				}
+			}
+			fn to_signed_grid_xy<T>(&self, _grid:&Grid<T>) -> (isize, isize) {
+				let $indexer = self;
+				let (x, y) = $xy;
+				(x as isize, y as isize)
+			}
+		}
+	};
+}
+implement_signed_indexer!([i32; 2], |indexer| (indexer[0], indexer[1]));
+implement_signed_indexer!([isize; 2], |indexer| (indexer[0], indexer[1]));
+implement_signed_indexer!((i32, i32), |indexer| *indexer);
+implement_signed_indexer!((isize, isize), |indexer| *indexer);
\ No newline at end of file
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-	use crate::Grid;
+	use crate::{ Grid, GridIndexer };
 
 
 	#[test]
@@ -28,4 +28,38 @@ mod tests {
 		assert_eq!(grid.xy_is_valid(10, 10), false);
 		assert_eq!(grid.xy_is_valid(10, 100), false);
 	}
+	#[test]
+	fn test_grid_get() {
+		let mut grid:Grid<i32> = Grid::new((0..6).collect(), 3, 2);
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(grid.get([2, 1]), Some(&5));
+		assert_eq!(grid.get((3, 0)), None);
+		assert_eq!(grid.get([0, 2]), None);
+		assert_eq!(grid.get(5), Some(&5));
+		assert_eq!(grid.get(6), None);
+		assert_eq!(grid.get([-1, 0]), None);
+		assert_eq!(grid.get((1isize, 1isize)), Some(&4));
+
+		*grid.get_mut([1, 0]).unwrap() = 10;
+		assert_eq!(grid.get_mut([1, -1]), None);
+		assert_eq!(grid.data(), &[0, 10, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn test_signed_grid_indexers() {
+		let grid:Grid<i32> = Grid::new((0..6).collect(), 3, 2);
+
+		assert_eq!(grid[[2i32, 1i32]], 5);
+		assert_eq!(grid[(1isize, 0isize)], 1);
+		assert_eq!([-1i32, 0i32].try_to_grid_index(&grid), None);
+		assert_eq!((1isize, 1isize).to_grid_xy(&grid), (1, 1));
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_signed_grid_indexer_out_of_bounds() {
+		let grid:Grid<i32> = Grid::new((0..6).collect(), 3, 2);
+		let _value:i32 = grid[[-1i32, 0i32]];
+	}
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
-use std::{ slice::{ Iter, IterMut }, vec::IntoIter };
-use crate::Grid;
+use std::{ slice::{ Chunks, ChunksMut, Iter, IterMut }, vec::IntoIter };
+use crate::{ Grid, GridIndexer, GridView, Neighborhood };
 
 
 
@@ -20,6 +20,73 @@ impl<T> Grid<T> {
 			index: 0
 		}
 	}
+
+	/// Iterate over the rows of the grid.
+	pub fn rows(&self) -> Chunks<'_, T> {
+		self.data.chunks(self.width.max(1))
+	}
+
+	/// Iterate over the mutable rows of the grid.
+	pub fn rows_mut(&mut self) -> ChunksMut<'_, T> {
+		self.data.chunks_mut(self.width.max(1))
+	}
+
+	/// Iterate over the columns of the grid. Each column is a view with a width of 1.
+	pub fn columns(&self) -> GridColumns<'_, T> {
+		GridColumns {
+			grid: self,
+			x: 0
+		}
+	}
+
+	/// Iterate over all possible windows of the given size with their top-left position, moving one pixel at a time in reading order.
+	pub fn windows(&self, width:usize, height:usize) -> GridWindows<'_, T> {
+		assert!(width > 0 && height > 0, "Window size should be at least 1x1.");
+		GridWindows {
+			grid: self,
+			width,
+			height,
+			end_x: (self.width + 1).saturating_sub(width),
+			end_y: (self.height + 1).saturating_sub(height),
+			x: 0,
+			y: 0
+		}
+	}
+
+	/// Iterate over non-overlapping tiles of the given size with their top-left position, in reading order. Tiles on the right and bottom edge are cropped when the grid size is not a multiple of the tile size.
+	pub fn tiles(&self, width:usize, height:usize) -> GridTiles<'_, T> {
+		self.create_tiles(width, height, true)
+	}
+
+	/// Iterate over non-overlapping tiles of the given size with their top-left position, in reading order. Pixels on the right and bottom edge that do not fill an entire tile are skipped.
+	pub fn exact_tiles(&self, width:usize, height:usize) -> GridTiles<'_, T> {
+		self.create_tiles(width, height, false)
+	}
+
+	/// Create a tile iterator.
+	fn create_tiles(&self, width:usize, height:usize, include_partial_tiles:bool) -> GridTiles<'_, T> {
+		assert!(width > 0 && height > 0, "Tile size should be at least 1x1.");
+		let (end_x, end_y) = if include_partial_tiles { (self.width, self.height) } else { (self.width - self.width % width, self.height - self.height % height) };
+		GridTiles {
+			grid: self,
+			width,
+			height,
+			end_x,
+			end_y,
+			x: 0,
+			y: 0
+		}
+	}
+
+	/// Get the four orthogonal neighbors of the given position with their position.
+	pub fn neighbors_of<U>(&self, position:U) -> Vec<([usize; 2], &T)> where U:GridIndexer {
+		self.neighbors_of_in(position, &Neighborhood::four_connected())
+	}
+
+	/// Get the neighbors of the given position in the given neighborhood with their position.
+	pub fn neighbors_of_in<U>(&self, position:U, neighborhood:&Neighborhood) -> Vec<([usize; 2], &T)> where U:GridIndexer {
+		neighborhood.neighbor_indexes(position.to_grid_index(self), self.width, self.height).map(|index| (self.index_to_xy(index), &self.data[index])).collect()
+	}
 }
 impl<T> Grid<T> where Grid<T>:Sized {
 
@@ -111,4 +178,80 @@ impl<'a, T> Iterator for PixelIteratorMut<'a, T> {
 			None
 		}
 	}
+}
+
+
+
+pub struct GridColumns<'a, T> {
+	grid:&'a Grid<T>,
+	x:usize
+}
+impl<'a, T> Iterator for GridColumns<'a, T> {
+	type Item = GridView<'a, T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.x < self.grid.width {
+			let column:GridView<T> = self.grid.view([self.x, 0, 1, self.grid.height]);
+			self.x += 1;
+			Some(column)
+		} else {
+			None
+		}
+	}
+}
+
+
+
+pub struct GridWindows<'a, T> {
+	grid:&'a Grid<T>,
+	width:usize,
+	height:usize,
+	end_x:usize,
+	end_y:usize,
+	x:usize,
+	y:usize
+}
+impl<'a, T> Iterator for GridWindows<'a, T> {
+	type Item = ([usize; 2], GridView<'a, T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.x >= self.end_x || self.y >= self.end_y {
+			return None;
+		}
+		let window:Self::Item = ([self.x, self.y], self.grid.view([self.x, self.y, self.width, self.height]));
+		self.x += 1;
+		if self.x == self.end_x {
+			self.x = 0;
+			self.y += 1;
+		}
+		Some(window)
+	}
+}
+
+
+
+pub struct GridTiles<'a, T> {
+	grid:&'a Grid<T>,
+	width:usize,
+	height:usize,
+	end_x:usize,
+	end_y:usize,
+	x:usize,
+	y:usize
+}
+impl<'a, T> Iterator for GridTiles<'a, T> {
+	type Item = ([usize; 2], GridView<'a, T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.x >= self.end_x || self.y >= self.end_y {
+			return None;
+		}
+		let tile:Self::Item = ([self.x, self.y], self.grid.view([self.x, self.y, self.width.min(self.end_x - self.x), self.height.min(self.end_y - self.y)]));
+		self.x += self.width;
+		if self.x >= self.end_x {
+			self.x = 0;
+			self.y += self.height;
+		}
+		Some(tile)
+	}
 }
\ No newline at end of file
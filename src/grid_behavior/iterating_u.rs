@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-	use crate::Grid;
+	use crate::{ Grid, GridView, Neighborhood };
 
 
 
@@ -59,4 +59,51 @@ mod tests {
 
 		assert_eq!(grid.data(), &['o', 'o', 'x', 'o', 'o', ' ', 'x', ' ', 'x']);
 	}
+
+	#[test]
+	fn test_grid_rows_and_columns() {
+		let mut grid:Grid<usize> = Grid::new((0..6).collect(), 3, 2);
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(grid.rows().collect::<Vec<&[usize]>>(), vec![&[0, 1, 2][..], &[3, 4, 5][..]]);
+		assert_eq!(grid.columns().map(|column| column.iter().copied().collect::<Vec<usize>>()).collect::<Vec<Vec<usize>>>(), vec![vec![0, 3], vec![1, 4], vec![2, 5]]);
+		for (row_index, row) in grid.rows_mut().enumerate() {
+			row.fill(row_index);
+		}
+		assert_eq!(grid.data, vec![0, 0, 0, 1, 1, 1]);
+	}
+
+	#[test]
+	fn test_grid_windows() {
+		let grid:Grid<usize> = Grid::new((0..12).collect(), 4, 3);
+		println!("[grid]\n{grid}\n");
+		let windows:Vec<([usize; 2], GridView<usize>)> = grid.windows(3, 2).collect();
+
+		assert_eq!(windows.iter().map(|(position, _)| *position).collect::<Vec<[usize; 2]>>(), vec![[0, 0], [1, 0], [0, 1], [1, 1]]);
+		assert_eq!(windows[3].1.iter().copied().collect::<Vec<usize>>(), vec![5, 6, 7, 9, 10, 11]);
+		assert_eq!(grid.windows(5, 1).count(), 0);
+	}
+
+	#[test]
+	fn test_grid_tiles() {
+		let grid:Grid<usize> = Grid::new((0..15).collect(), 5, 3);
+		println!("[grid]\n{grid}\n");
+		let tiles:Vec<([usize; 2], GridView<usize>)> = grid.tiles(2, 2).collect();
+
+		assert_eq!(tiles.iter().map(|(position, _)| *position).collect::<Vec<[usize; 2]>>(), vec![[0, 0], [2, 0], [4, 0], [0, 2], [2, 2], [4, 2]]);
+		assert_eq!(tiles.iter().map(|(_, tile)| [tile.width(), tile.height()]).collect::<Vec<[usize; 2]>>(), vec![[2, 2], [2, 2], [1, 2], [2, 1], [2, 1], [1, 1]]);
+		assert_eq!(tiles[5].1.iter().copied().collect::<Vec<usize>>(), vec![14]);
+		let exact_tiles:Vec<([usize; 2], GridView<usize>)> = grid.exact_tiles(2, 2).collect();
+		assert_eq!(exact_tiles.iter().map(|(position, _)| *position).collect::<Vec<[usize; 2]>>(), vec![[0, 0], [2, 0]]);
+	}
+
+	#[test]
+	fn test_grid_neighbors_of() {
+		let grid:Grid<usize> = Grid::new((0..9).collect(), 3, 3);
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(grid.neighbors_of([0, 1]), vec![([0, 0], &0), ([1, 1], &4), ([0, 2], &6)]);
+		assert_eq!(grid.neighbors_of_in(4, &Neighborhood::eight_connected()).len(), 8);
+		assert_eq!(grid.neighbors_of_in([2, 2], &Neighborhood::eight_connected()).into_iter().map(|(_, value)| *value).collect::<Vec<usize>>(), vec![7, 5, 4]);
+	}
 }
\ No newline at end of file
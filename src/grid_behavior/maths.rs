@@ -1,38 +1,101 @@
+use crate::Grid;
+
+
+
 macro_rules! impl_maths {
 	($trait:ident, $fn_name:ident, $modification:expr) => {
-		impl<T> std::ops::$trait<crate::Grid<T>> for crate::Grid<T> where T:std::ops::$trait<Output=T> + Clone + 'static {
-			type Output = crate::Grid<T>;
+		impl<T> std::ops::$trait<&Grid<T>> for Grid<T> where T:std::ops::$trait<Output=T> + Clone + 'static {
+			type Output = Grid<T>;
+		
+			/// Combine the grid with another grid of the same size pixel by pixel. Panics if the grids are not the same size, use `zip_map` to handle that case.
+			fn $fn_name(mut self, modification:&Grid<T>) -> Self::Output {
+				assert_eq!([self.width, self.height], [modification.width, modification.height], "Cannot combine grids of different sizes.");
+				let modification_function:&'static dyn Fn(T, T) -> T = $modification;
+				for (value, modification_value) in self.data.iter_mut().zip(&modification.data) {
+					*value = modification_function(value.clone(), modification_value.clone());
+				}
+				self
+			}
+		}
+		impl<T> std::ops::$trait<Grid<T>> for Grid<T> where T:std::ops::$trait<Output=T> + Clone + 'static {
+			type Output = Grid<T>;
+		
+			fn $fn_name(self, modification:Grid<T>) -> Self::Output {
+				std::ops::$trait::$fn_name(self, &modification)
+			}
+		}
+		impl<T> std::ops::$trait<&Grid<T>> for &Grid<T> where T:std::ops::$trait<Output=T> + Clone + 'static {
+			type Output = Grid<T>;
 		
-			fn $fn_name(mut self, modification:crate::Grid<T>) -> Self::Output {
+			fn $fn_name(self, modification:&Grid<T>) -> Self::Output {
+				std::ops::$trait::$fn_name(self.clone(), modification)
+			}
+		}
+		impl<T> std::ops::$trait<T> for Grid<T> where T:std::ops::$trait<Output=T> + Clone + 'static {
+			type Output = Grid<T>;
+		
+			fn $fn_name(mut self, modification:T) -> Self::Output {
 				let modification_function:&'static dyn Fn(T, T) -> T = $modification;
-				for y in 0..self.height.min(modification.height) {
-					for x in 0..self.width.min(modification.width) {
-						let left_index:usize = y * self.width + x;
-						let right_index:usize = y * modification.width + x;
-						self[left_index] = modification_function(self[left_index].clone(), modification[right_index].clone())
-					}
+				for value in &mut self.data {
+					*value = modification_function(value.clone(), modification.clone());
 				}
 				self
 			}
 		}
+		impl<T> std::ops::$trait<T> for &Grid<T> where T:std::ops::$trait<Output=T> + Clone + 'static {
+			type Output = Grid<T>;
+		
+			fn $fn_name(self, modification:T) -> Self::Output {
+				std::ops::$trait::$fn_name(self.clone(), modification)
+			}
+		}
 	};
 }
 macro_rules! impl_maths_assign {
 	($trait:ident, $fn_name:ident, $modification:expr) => {
-		impl<T> std::ops::$trait<crate::Grid<T>> for crate::Grid<T> where T:std::ops::$trait + Clone + 'static {
-			fn $fn_name(&mut self, modification:crate::Grid<T>) {
+		impl<T> std::ops::$trait<&Grid<T>> for Grid<T> where T:std::ops::$trait + Clone + 'static {
+			/// Modify the grid with another grid of the same size pixel by pixel. Panics if the grids are not the same size, use `zip_map` to handle that case.
+			fn $fn_name(&mut self, modification:&Grid<T>) {
+				assert_eq!([self.width, self.height], [modification.width, modification.height], "Cannot modify grid with a grid of a different size.");
+				let modification_function:&'static dyn Fn(&mut T, T) = $modification;
+				for (value, modification_value) in self.data.iter_mut().zip(&modification.data) {
+					modification_function(value, modification_value.clone());
+				}
+			}
+		}
+		impl<T> std::ops::$trait<Grid<T>> for Grid<T> where T:std::ops::$trait + Clone + 'static {
+			fn $fn_name(&mut self, modification:Grid<T>) {
+				std::ops::$trait::$fn_name(self, &modification)
+			}
+		}
+		impl<T> std::ops::$trait<T> for Grid<T> where T:std::ops::$trait + Clone + 'static {
+			fn $fn_name(&mut self, modification:T) {
 				let modification_function:&'static dyn Fn(&mut T, T) = $modification;
-				for y in 0..self.height.min(modification.height) {
-					for x in 0..self.width.min(modification.width) {
-						let left_index:usize = y * self.width + x;
-						let right_index:usize = y * modification.width + x;
-						modification_function(&mut self[left_index], modification[right_index].clone())
-					}
+				for value in &mut self.data {
+					modification_function(value, modification.clone());
 				}
 			}
 		}
 	};
 }
+macro_rules! impl_unary_maths {
+	($trait:ident, $fn_name:ident) => {
+		impl<T> std::ops::$trait for Grid<T> where T:std::ops::$trait<Output=T> {
+			type Output = Grid<T>;
+		
+			fn $fn_name(self) -> Self::Output {
+				self.map(|value| std::ops::$trait::$fn_name(value))
+			}
+		}
+		impl<T> std::ops::$trait for &Grid<T> where T:std::ops::$trait<Output=T> + Clone {
+			type Output = Grid<T>;
+		
+			fn $fn_name(self) -> Self::Output {
+				self.map_ref(|value| std::ops::$trait::$fn_name(value.clone()))
+			}
+		}
+	};
+}
 
 
 
@@ -44,6 +107,7 @@ impl_maths_assign!(AddAssign, add_assign, &|left, right| *left += right);
 impl_maths_assign!(SubAssign, sub_assign, &|left, right| *left -= right);
 impl_maths_assign!(MulAssign, mul_assign, &|left, right| *left *= right);
 impl_maths_assign!(DivAssign, div_assign, &|left, right| *left /= right);
+impl_unary_maths!(Neg, neg);
 
 
 
@@ -56,4 +120,5 @@ impl_maths_assign!(BitAndAssign, bitand_assign, &|left, right| *left &= right.cl
 impl_maths_assign!(BitOrAssign, bitor_assign, &|left, right| *left |= right.clone());
 impl_maths_assign!(BitXorAssign, bitxor_assign, &|left, right| *left ^= right.clone());
 impl_maths_assign!(ShlAssign, shl_assign, &|left, right| *left <<= right.clone());
-impl_maths_assign!(ShrAssign, shr_assign, &|left, right| *left >>= right.clone());
\ No newline at end of file
+impl_maths_assign!(ShrAssign, shr_assign, &|left, right| *left >>= right.clone());
+impl_unary_maths!(Not, not);
\ No newline at end of file
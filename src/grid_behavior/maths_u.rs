@@ -118,4 +118,59 @@ mod tests {
 			">>"
 		);
 	}
+
+
+
+	#[test]
+	fn test_reference_operations() {
+		let grid:Grid<i32> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		let modifier:Grid<i32> = Grid::new(vec![10, 20, 30, 40], 2, 2);
+		let result:Grid<i32> = &grid + &modifier;
+		println!("[result]\n{result}\n");
+
+		assert_eq!(result.data, vec![11, 22, 33, 44]);
+		assert_eq!((&modifier - &grid).data, vec![9, 18, 27, 36]);
+		assert_eq!((grid.clone() * &modifier).data, vec![10, 40, 90, 160]);
+		let mut assigned:Grid<i32> = grid.clone();
+		assigned += &modifier;
+		assert_eq!(assigned, result);
+		assert_eq!(grid.data, vec![1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn test_scalar_operations() {
+		let grid:Grid<i32> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		let result:Grid<i32> = &grid * 3;
+		println!("[result]\n{result}\n");
+
+		assert_eq!(result.data, vec![3, 6, 9, 12]);
+		assert_eq!((grid.clone() + 1).data, vec![2, 3, 4, 5]);
+		assert_eq!((&grid << 1).data, vec![2, 4, 6, 8]);
+		let mut assigned:Grid<i32> = grid.clone();
+		assigned -= 1;
+		assert_eq!(assigned.data, vec![0, 1, 2, 3]);
+	}
+
+	#[test]
+	fn test_unary_operations() {
+		let grid:Grid<i32> = Grid::new(vec![1, -2, 3, 0], 2, 2);
+
+		assert_eq!((-&grid).data, vec![-1, 2, -3, 0]);
+		assert_eq!((-grid.clone()).data, vec![-1, 2, -3, 0]);
+		assert_eq!((!&grid).data, vec![!1, !-2, !3, !0]);
+		assert_eq!((!Grid::new(vec![true, false], 2, 1)).data, vec![false, true]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_size_mismatch() {
+		let _ = Grid::new(vec![1, 2, 3, 4], 2, 2) + Grid::new(vec![1, 2, 3], 3, 1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_assign_size_mismatch() {
+		let mut grid:Grid<i32> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		grid *= &Grid::new(vec![1, 2, 3, 4, 5, 6], 3, 2);
+	}
 }
\ No newline at end of file
@@ -1,5 +1,9 @@
 mod indexer;
 mod indexer_u;
+mod edge_policy;
+mod edge_policy_u;
+mod neighborhood;
+mod neighborhood_u;
 mod iterating;
 mod iterating_u;
 mod maths;
@@ -8,6 +12,28 @@ mod converter;
 mod converter_u;
 mod appending;
 mod appending_u;
+mod splitting;
+mod splitting_u;
+mod canvas;
+mod canvas_u;
+mod transforming;
+mod transforming_u;
+mod numeric;
+mod statistics;
+mod statistics_u;
+mod filtering;
+mod filtering_u;
+mod resizing;
+mod resizing_u;
+mod pyramid;
+mod pyramid_u;
 
 pub use indexer::GridIndexer;
-pub use iterating::{ PixelIterator, PixelIteratorMut };
\ No newline at end of file
+pub use edge_policy::EdgePolicy;
+pub use neighborhood::Neighborhood;
+pub use appending::GridAlignment;
+pub use iterating::{ PixelIterator, PixelIteratorMut, GridColumns, GridWindows, GridTiles };
+pub use numeric::GridNumeric;
+pub use filtering::Kernel;
+pub use resizing::{ Interpolation, Interpolatable };
+pub use pyramid::{ PoolingMethod, GridPyramid };
\ No newline at end of file
@@ -0,0 +1,72 @@
+use crate::{ Grid, GridIndexer };
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Neighborhood {
+	offsets:Vec<[isize; 2]>
+}
+impl Neighborhood {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a von Neumann neighborhood, connecting each position to the 4 orthogonally adjacent positions.
+	pub fn four_connected() -> Neighborhood {
+		Neighborhood::custom(vec![[-1, 0], [0, -1], [1, 0], [0, 1]])
+	}
+
+	/// Create a Moore neighborhood, connecting each position to the 8 orthogonally and diagonally adjacent positions.
+	pub fn eight_connected() -> Neighborhood {
+		Neighborhood::custom(vec![[-1, 0], [0, -1], [1, 0], [0, 1], [-1, -1], [1, -1], [1, 1], [-1, 1]])
+	}
+
+	/// Create a neighborhood from a custom stencil of XY offsets. An offset of [0, 0] is ignored.
+	pub fn custom(offsets:Vec<[isize; 2]>) -> Neighborhood {
+		Neighborhood {
+			offsets: offsets.into_iter().filter(|offset| *offset != [0, 0]).collect()
+		}
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the XY offsets of the neighborhood.
+	pub fn offsets(&self) -> &[[isize; 2]] {
+		&self.offsets
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Get the indexes of the neighbors of the given index that fall inside a grid of the given size.
+	pub(crate) fn neighbor_indexes(&self, index:usize, width:usize, height:usize) -> impl Iterator<Item = usize> + '_ {
+		let x:isize = (index % width) as isize;
+		let y:isize = (index / width) as isize;
+		self.offsets.iter().filter_map(move |[offset_x, offset_y]| {
+			let neighbor_x:isize = x + offset_x;
+			let neighbor_y:isize = y + offset_y;
+			if neighbor_x >= 0 && neighbor_y >= 0 && (neighbor_x as usize) < width && (neighbor_y as usize) < height {
+				Some(neighbor_y as usize * width + neighbor_x as usize)
+			} else {
+				None
+			}
+		})
+	}
+}
+impl Default for Neighborhood {
+	fn default() -> Self {
+		Neighborhood::four_connected()
+	}
+}
+
+
+
+impl<T> Grid<T> {
+
+	/// Get the available neighbors for a specific index in the given neighborhood.
+	pub fn index_neighbors_in<U>(&self, index:U, neighborhood:&Neighborhood) -> Vec<usize> where U:GridIndexer {
+		neighborhood.neighbor_indexes(index.to_grid_index(self), self.width, self.height).collect()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Grid, GridRegion, Neighborhood };
+
+
+
+	#[test]
+	fn test_neighbor_indexes() {
+		let grid:Grid<usize> = Grid::new((0..9).collect(), 3, 3);
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(grid.index_neighbors(4), vec![3, 1, 5, 7]);
+		assert_eq!(grid.index_neighbors(3), vec![0, 4, 6]);
+		assert_eq!(grid.index_neighbors([0, 0]), vec![1, 3]);
+		assert_eq!(grid.index_neighbors_in(4, &Neighborhood::eight_connected()), vec![3, 1, 5, 7, 0, 2, 8, 6]);
+		assert_eq!(grid.index_neighbors_in([2, 2], &Neighborhood::eight_connected()), vec![7, 5, 4]);
+		assert_eq!(grid.index_neighbors_in(0, &Neighborhood::custom(vec![[0, 0], [2, 0], [0, 2], [-1, 0]])), vec![2, 6]);
+	}
+
+	#[test]
+	fn test_region_eight_connected() {
+		let grid:Grid<char> = Grid::new(vec!['x', ' ', ' ', ' ', 'x', ' ', ' ', ' ', 'x'], 3, 3);
+		println!("[grid]\n{grid}\n");
+		let four_region:GridRegion = grid.region_at_eq([0, 0]);
+		let eight_region:GridRegion = grid.region_at_eq_with_neighborhood([0, 0], &Neighborhood::eight_connected());
+		println!("[eight connected region]\n{}\n", eight_region.grid());
+
+		assert_eq!(four_region.grid().data, vec![true, false, false, false, false, false, false, false, false]);
+		assert_eq!(eight_region.grid().data, vec![true, false, false, false, true, false, false, false, true]);
+		assert_eq!(grid.region_at_with_neighborhood([0, 0], &Neighborhood::eight_connected(), |left, right| left == right).grid(), eight_region.grid());
+	}
+
+	#[test]
+	fn test_path_finding_diagonal() {
+		let maze:[&str; 4] = [
+			"x   ",
+			" x  ",
+			" x  ",
+			"  xx"
+		];
+		let grid:Grid<char> = Grid::new(maze.iter().flat_map(|line| line.chars()).collect(), 4, 4);
+		println!("[grid]\n{grid}\n");
+
+		assert!(grid.find_path([0, 0], [3, 3]).is_err());
+		assert_eq!(grid.find_path_with_neighborhood([0, 0], [3, 3], &Neighborhood::eight_connected()).unwrap(), vec![[0, 0], [1, 1], [1, 2], [2, 3], [3, 3]]);
+		assert_eq!(
+			grid.find_path_weighed_with_neighborhood([0, 0], [3, 3], &Neighborhood::eight_connected(), |_, (_, to)| if *to == 'x' { Some(1) } else { None }).unwrap(),
+			vec![[0, 0], [1, 1], [1, 2], [2, 3], [3, 3]]
+		);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+pub trait GridNumeric:Copy + PartialOrd + Default + Send + Sync + 'static {
+
+	/// Convert the value to a 64-bit float for calculations.
+	fn to_f64(&self) -> f64;
+
+	/// Create the value from a 64-bit float. Integer types round the value and clamp it to their own range.
+	fn from_f64(value:f64) -> Self;
+}
+
+
+
+/* INTEGER IMPLEMENTATIONS */
+
+macro_rules! implement_numeric_for_integer {
+	($type:ty) => {
+		impl GridNumeric for $type {
+			fn to_f64(&self) -> f64 {
+				*self as f64
+			}
+			fn from_f64(value:f64) -> Self {
+				value.round() as $type // Float to integer casts saturate at the bounds of the type.
+			}
+		}
+	};
+}
+implement_numeric_for_integer!(u8);
+implement_numeric_for_integer!(u16);
+implement_numeric_for_integer!(u32);
+implement_numeric_for_integer!(u64);
+implement_numeric_for_integer!(u128);
+implement_numeric_for_integer!(usize);
+implement_numeric_for_integer!(i8);
+implement_numeric_for_integer!(i16);
+implement_numeric_for_integer!(i32);
+implement_numeric_for_integer!(i64);
+implement_numeric_for_integer!(i128);
+implement_numeric_for_integer!(isize);
+
+
+
+/* FLOAT IMPLEMENTATIONS */
+
+macro_rules! implement_numeric_for_float {
+	($type:ty) => {
+		impl GridNumeric for $type {
+			fn to_f64(&self) -> f64 {
+				*self as f64
+			}
+			fn from_f64(value:f64) -> Self {
+				value as $type
+			}
+		}
+	};
+}
+implement_numeric_for_float!(f32);
+implement_numeric_for_float!(f64);
\ No newline at end of file
@@ -0,0 +1,96 @@
+use crate::{ Grid, Interpolatable };
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PoolingMethod {
+	Average, // Interpolate all cells in the block with equal weights.
+	Mode // Take the most common value in the block, the first value in reading order wins ties.
+}
+
+
+
+impl<T> Grid<T> where T:Interpolatable + PartialEq {
+
+	/// Create a copy of the grid at half the size, where each cell combines a 2x2 block of cells using the given pooling method. Odd sizes round up, the blocks on the right and bottom edge then hold fewer cells.
+	pub fn downsampled(&self, pooling:PoolingMethod) -> Grid<T> {
+		let width:usize = self.width.div_ceil(2);
+		let height:usize = self.height.div_ceil(2);
+		let mut block:Vec<&T> = Vec::with_capacity(4);
+		let mut data:Vec<T> = Vec::with_capacity(width * height);
+		for y in 0..height {
+			for x in 0..width {
+
+				// Collect the cells of the block.
+				block.clear();
+				for source_y in y * 2..(y * 2 + 2).min(self.height) {
+					for source_x in x * 2..(x * 2 + 2).min(self.width) {
+						block.push(&self.data[source_y * self.width + source_x]);
+					}
+				}
+
+				// Combine the block.
+				data.push(match pooling {
+					PoolingMethod::Average => {
+						let weight:f32 = 1.0 / block.len() as f32;
+						T::interpolate(&block.iter().map(|value| (*value, weight)).collect::<Vec<(&T, f32)>>())
+					},
+					PoolingMethod::Mode => {
+						let counts:Vec<usize> = block.iter().map(|value| block.iter().filter(|other| *other == value).count()).collect();
+						let max_count:usize = *counts.iter().max().unwrap();
+						block[counts.iter().position(|count| *count == max_count).unwrap()].clone()
+					}
+				});
+			}
+		}
+		Grid::new(data, width, height)
+	}
+
+	/// Create a pyramid of the grid with at most the given amount of levels.
+	pub fn pyramid(&self, max_levels:usize, pooling:PoolingMethod) -> GridPyramid<T> {
+		GridPyramid::new(self, max_levels, pooling)
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct GridPyramid<T> {
+	levels:Vec<Grid<T>>
+}
+impl<T> GridPyramid<T> where T:Interpolatable + PartialEq {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a pyramid where the first level is the given grid and each next level is downsampled from the previous. Stops adding levels at the given maximum or when a level cannot get any smaller.
+	pub fn new(grid:&Grid<T>, max_levels:usize, pooling:PoolingMethod) -> GridPyramid<T> {
+		let mut levels:Vec<Grid<T>> = vec![grid.clone()];
+		while levels.len() < max_levels.max(1) {
+			let last:&Grid<T> = &levels[levels.len() - 1];
+			if last.width <= 1 && last.height <= 1 {
+				break;
+			}
+			levels.push(last.downsampled(pooling));
+		}
+		GridPyramid { levels }
+	}
+}
+impl<T> GridPyramid<T> {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get all levels, from full size to smallest.
+	pub fn levels(&self) -> &[Grid<T>] {
+		&self.levels
+	}
+
+	/// Get the level at the given index, where 0 is the full size grid.
+	pub fn level(&self, index:usize) -> Option<&Grid<T>> {
+		self.levels.get(index)
+	}
+
+	/// Get the amount of levels in the pyramid.
+	pub fn level_count(&self) -> usize {
+		self.levels.len()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Color, Grid, GridPyramid, PoolingMethod };
+
+
+
+	#[test]
+	fn test_downsample_average() {
+		let grid:Grid<u8> = Grid::new(vec![0, 2, 10, 4, 20, 6, 8, 8, 9, 30, 40, 50], 4, 3);
+		println!("[grid]\n{grid}\n");
+		let downsampled:Grid<u8> = grid.downsampled(PoolingMethod::Average);
+		println!("[downsampled]\n{downsampled}\n");
+
+		assert_eq!([downsampled.width, downsampled.height], [2, 2]);
+		assert_eq!(downsampled.data(), &[7, 8, 20, 45]);
+	}
+
+	#[test]
+	fn test_downsample_mode() {
+		let grid:Grid<u8> = Grid::new(vec![1, 2, 3, 3, 5, 2, 4, 3, 9], 3, 3);
+		println!("[grid]\n{grid}\n");
+		let downsampled:Grid<u8> = grid.downsampled(PoolingMethod::Mode);
+		println!("[downsampled]\n{downsampled}\n");
+
+		assert_eq!([downsampled.width, downsampled.height], [2, 2]);
+		assert_eq!(downsampled.data(), &[1, 3, 4, 9]);
+	}
+
+	#[test]
+	fn test_downsample_color() {
+		let image:Grid<Color> = Grid::new(vec![Color(0xFF0000FF), Color(0xFF0000FF), Color(0xFFFF0000), Color(0x00000000)], 2, 2);
+		let downsampled:Grid<Color> = image.downsampled(PoolingMethod::Average);
+		println!("[downsampled]\n{downsampled}\n");
+
+		assert_eq!(downsampled.data(), &[Color(0xBF5500AA)]);
+	}
+
+	#[test]
+	fn test_pyramid_levels() {
+		let grid:Grid<u16> = Grid::new(vec![5; 10 * 6], 10, 6);
+		let pyramid:GridPyramid<u16> = grid.pyramid(10, PoolingMethod::Average);
+
+		assert_eq!(pyramid.level_count(), 5);
+		assert_eq!(pyramid.levels().iter().map(|level| [level.width, level.height]).collect::<Vec<[usize; 2]>>(), vec![[10, 6], [5, 3], [3, 2], [2, 1], [1, 1]]);
+		assert_eq!(pyramid.level(0), Some(&grid));
+		assert_eq!(pyramid.level(4).unwrap().data(), &[5]);
+		assert_eq!(grid.pyramid(2, PoolingMethod::Mode).level_count(), 2);
+		assert_eq!(grid.pyramid(0, PoolingMethod::Mode).level_count(), 1);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,143 @@
+use crate::{ Color, Grid, GridNumeric };
+
+
+
+const BICUBIC_COEFFICIENT:f32 = -0.5;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Interpolation {
+	Nearest,
+	Bilinear,
+	Bicubic
+}
+
+
+
+impl<T> Grid<T> where T:Clone {
+
+	/// Create a resized copy of the grid using nearest-neighbor sampling.
+	pub fn resized_nearest(&self, width:usize, height:usize) -> Grid<T> {
+		if width == 0 || height == 0 {
+			return Grid::new(Vec::new(), width, height);
+		}
+		assert!(!self.is_empty(), "Cannot resize an empty grid to a non-empty size.");
+
+		let source_x:Vec<usize> = (0..width).map(|x| ((2 * x + 1) * self.width / (2 * width)).min(self.width - 1)).collect();
+		let mut data:Vec<T> = Vec::with_capacity(width * height);
+		for y in 0..height {
+			let source_row_index:usize = ((2 * y + 1) * self.height / (2 * height)).min(self.height - 1) * self.width;
+			data.extend(source_x.iter().map(|x| self.data[source_row_index + x].clone()));
+		}
+		Grid::new(data, width, height)
+	}
+}
+impl<T> Grid<T> where T:Interpolatable {
+
+	/// Create a resized copy of the grid using the given interpolation.
+	pub fn resized(&self, width:usize, height:usize, interpolation:Interpolation) -> Grid<T> {
+		if width == 0 || height == 0 {
+			return Grid::new(Vec::new(), width, height);
+		}
+		assert!(!self.is_empty(), "Cannot resize an empty grid to a non-empty size.");
+
+		// Nearest-neighbor does not require any weights.
+		let tap_count:usize = match interpolation {
+			Interpolation::Nearest => return self.resized_nearest(width, height),
+			Interpolation::Bilinear => 2,
+			Interpolation::Bicubic => 4
+		};
+
+		// Calculate source indexes and weights per axis once, as they repeat for every row and column.
+		let x_taps:Vec<Vec<(usize, f32)>> = Self::resampling_taps(self.width, width, interpolation);
+		let y_taps:Vec<Vec<(usize, f32)>> = Self::resampling_taps(self.height, height, interpolation);
+
+		// Combine all taps for each pixel.
+		let mut samples:Vec<(&T, f32)> = Vec::with_capacity(tap_count * tap_count);
+		let mut data:Vec<T> = Vec::with_capacity(width * height);
+		for row_taps in &y_taps {
+			for column_taps in &x_taps {
+				samples.clear();
+				for (source_y, y_weight) in row_taps {
+					let source_row_index:usize = source_y * self.width;
+					for (source_x, x_weight) in column_taps {
+						samples.push((&self.data[source_row_index + source_x], y_weight * x_weight));
+					}
+				}
+				data.push(T::interpolate(&samples));
+			}
+		}
+		Grid::new(data, width, height)
+	}
+
+	/// Create a copy of the grid scaled by the given factor using the given interpolation.
+	pub fn scaled(&self, factor:f32, interpolation:Interpolation) -> Grid<T> {
+		self.resized((self.width as f32 * factor).round() as usize, (self.height as f32 * factor).round() as usize, interpolation)
+	}
+
+	/// For each position on an axis of the target size, get the source positions and their weights. Source and target positions are aligned by their centers.
+	fn resampling_taps(source_size:usize, target_size:usize, interpolation:Interpolation) -> Vec<Vec<(usize, f32)>> {
+		let scale:f32 = source_size as f32 / target_size as f32;
+		let max_index:isize = source_size as isize - 1;
+		(0..target_size).map(|target_position| {
+			let source_position:f32 = ((target_position as f32 + 0.5) * scale - 0.5).max(0.0).min(max_index as f32);
+			let source_start:f32 = source_position.floor();
+			let offset:f32 = source_position - source_start;
+			let source_start:isize = source_start as isize;
+			match interpolation {
+				Interpolation::Bicubic => (-1..3).map(|tap| ((source_start + tap).clamp(0, max_index) as usize, Self::bicubic_weight(tap as f32 - offset))).collect(),
+				_ => vec![(source_start as usize, 1.0 - offset), ((source_start + 1).min(max_index) as usize, offset)]
+			}
+		}).collect()
+	}
+
+	/// Get the bicubic kernel weight for a sample at the given distance.
+	fn bicubic_weight(distance:f32) -> f32 {
+		let distance:f32 = distance.abs();
+		if distance <= 1.0 {
+			(BICUBIC_COEFFICIENT + 2.0) * distance.powi(3) - (BICUBIC_COEFFICIENT + 3.0) * distance.powi(2) + 1.0
+		} else if distance < 2.0 {
+			BICUBIC_COEFFICIENT * (distance.powi(3) - 5.0 * distance.powi(2) + 8.0 * distance - 4.0)
+		} else {
+			0.0
+		}
+	}
+}
+
+
+
+pub trait Interpolatable:Clone {
+
+	/// Combine weighed samples into a single value. The weights add up to 1.0, but individual weights may be negative.
+	fn interpolate(samples:&[(&Self, f32)]) -> Self;
+}
+impl<T> Interpolatable for T where T:GridNumeric {
+	fn interpolate(samples:&[(&Self, f32)]) -> Self {
+		T::from_f64(samples.iter().map(|(value, weight)| value.to_f64() * *weight as f64).sum())
+	}
+}
+impl Interpolatable for Color {
+	fn interpolate(samples:&[(&Self, f32)]) -> Self {
+
+		// Interpolate using premultiplied alpha, so invisible colors do not bleed into their neighbors.
+		let mut argb:[f32; 4] = [0.0; 4];
+		for (color, weight) in samples {
+			let alpha:f32 = *color.a() as f32 * weight;
+			argb[0] += alpha;
+			argb[1] += *color.r() as f32 * alpha;
+			argb[2] += *color.g() as f32 * alpha;
+			argb[3] += *color.b() as f32 * alpha;
+		}
+		if argb[0] <= 0.0 {
+			return Color(0);
+		}
+		let alpha:f32 = argb[0];
+		Color(u32::from_be_bytes([
+			alpha.round().min(255.0) as u8,
+			(argb[1] / alpha).round().clamp(0.0, 255.0) as u8,
+			(argb[2] / alpha).round().clamp(0.0, 255.0) as u8,
+			(argb[3] / alpha).round().clamp(0.0, 255.0) as u8
+		]))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Color, Grid, Interpolation };
+
+
+
+	#[test]
+	fn test_resize_nearest() {
+		let grid:Grid<char> = Grid::new(vec!['a', 'b', 'c', 'd'], 2, 2);
+		println!("[grid]\n{grid}\n");
+		let upscaled:Grid<char> = grid.resized_nearest(4, 4);
+		println!("[upscaled]\n{upscaled}\n");
+		let downscaled:Grid<char> = upscaled.resized_nearest(2, 2);
+		println!("[downscaled]\n{downscaled}\n");
+
+		assert_eq!([upscaled.width, upscaled.height], [4, 4]);
+		assert_eq!(upscaled.data_2d(), [['a', 'a', 'b', 'b'], ['a', 'a', 'b', 'b'], ['c', 'c', 'd', 'd'], ['c', 'c', 'd', 'd']]);
+		assert_eq!(downscaled, grid);
+	}
+
+	#[test]
+	fn test_resize_bilinear() {
+		let grid:Grid<f32> = Grid::new(vec![0.0, 4.0], 2, 1);
+		println!("[grid]\n{grid}\n");
+		let upscaled:Grid<f32> = grid.resized(4, 1, Interpolation::Bilinear);
+		println!("[upscaled]\n{upscaled}\n");
+
+		assert_eq!([upscaled.width, upscaled.height], [4, 1]);
+		assert_eq!(upscaled.data(), &[0.0, 1.0, 3.0, 4.0]);
+		assert_eq!(grid.resized(1, 1, Interpolation::Bilinear).data(), &[2.0]);
+	}
+
+	#[test]
+	fn test_resize_bicubic() {
+		let grid:Grid<u8> = Grid::new((0..16).map(|value| value * 10).collect(), 4, 4);
+		println!("[grid]\n{grid}\n");
+		let resized:Grid<u8> = grid.resized(4, 4, Interpolation::Bicubic);
+		println!("[resized]\n{resized}\n");
+		let upscaled:Grid<u8> = grid.resized(8, 8, Interpolation::Bicubic);
+		println!("[upscaled]\n{upscaled}\n");
+
+		assert_eq!(resized, grid);
+		assert_eq!([upscaled.width, upscaled.height], [8, 8]);
+		assert!(upscaled.data_2d().iter().all(|row| row.windows(2).all(|pair| pair[0] <= pair[1])));
+	}
+
+	#[test]
+	fn test_resize_clamps_integers() {
+		let grid:Grid<u8> = Grid::new(vec![0, 255, 255, 0], 4, 1);
+		let upscaled:Grid<u8> = grid.resized(16, 1, Interpolation::Bicubic);
+		println!("[upscaled]\n{upscaled}\n");
+
+		assert_eq!(upscaled.data()[0], 0);
+		assert_eq!(upscaled.iter().max(), Some(&255));
+	}
+
+	#[test]
+	fn test_resize_color_alpha() {
+		let image:Grid<Color> = Grid::new(vec![Color(0x00FF0000), Color(0xFF0000FF)], 2, 1);
+		println!("[image]\n{image}\n");
+		let resized:Grid<Color> = image.resized(1, 1, Interpolation::Bilinear);
+		println!("[resized]\n{resized}\n");
+
+		assert_eq!(resized.data(), &[Color(0x800000FF)]);
+	}
+
+	#[test]
+	fn test_scaled() {
+		let grid:Grid<u16> = Grid::new(vec![100; 6], 3, 2);
+		let scaled:Grid<u16> = grid.scaled(2.5, Interpolation::Bilinear);
+
+		assert_eq!([scaled.width, scaled.height], [8, 5]);
+		assert!(scaled.iter().all(|value| *value == 100));
+	}
+}
\ No newline at end of file
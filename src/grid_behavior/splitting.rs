@@ -0,0 +1,32 @@
+use crate::Grid;
+
+
+
+impl<T> Grid<T> where T:Clone {
+
+	/// Split the grid into a grid of tiles with the given amount of columns and rows. When the size of the grid is not divisible by the amount of tiles, the first columns and rows get one pixel extra. This is the inverse of `flatten_grid`.
+	pub fn split_into(&self, columns:usize, rows:usize) -> Grid<Grid<T>> {
+		assert!(columns > 0 && rows > 0, "Cannot split grid into {columns}x{rows} tiles.");
+		let col_widths:Vec<usize> = (0..columns).map(|column| self.width / columns + if column < self.width % columns { 1 } else { 0 }).collect();
+		let row_heights:Vec<usize> = (0..rows).map(|row| self.height / rows + if row < self.height % rows { 1 } else { 0 }).collect();
+		self.split_by(&col_widths, &row_heights)
+	}
+
+	/// Split the grid into a grid of tiles with the given column widths and row heights. Pixels beyond the total width and height of the tiles are ignored. This is the inverse of `flatten_grid`.
+	pub fn split_by(&self, col_widths:&[usize], row_heights:&[usize]) -> Grid<Grid<T>> {
+		assert!(col_widths.iter().sum::<usize>() <= self.width, "Cannot split grid of width {} into columns with a total width of {}.", self.width, col_widths.iter().sum::<usize>());
+		assert!(row_heights.iter().sum::<usize>() <= self.height, "Cannot split grid of height {} into rows with a total height of {}.", self.height, row_heights.iter().sum::<usize>());
+
+		let mut tiles:Vec<Grid<T>> = Vec::with_capacity(col_widths.len() * row_heights.len());
+		let mut cursor:[usize; 2] = [0, 0];
+		for row_height in row_heights {
+			for col_width in col_widths {
+				tiles.push(self.view([cursor[0], cursor[1], *col_width, *row_height]).to_grid());
+				cursor[0] += col_width;
+			}
+			cursor[0] = 0;
+			cursor[1] += row_height;
+		}
+		Grid::new(tiles, col_widths.len(), row_heights.len())
+	}
+}
\ No newline at end of file
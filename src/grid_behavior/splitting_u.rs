@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Grid, GridAlignment };
+
+
+
+	#[test]
+	fn test_split_into() {
+		let grid:Grid<usize> = Grid::new((0..20).collect(), 5, 4);
+		println!("[grid]\n{grid}\n");
+		let tiles:Grid<Grid<usize>> = grid.split_into(2, 2);
+
+		assert_eq!([tiles.width, tiles.height], [2, 2]);
+		assert_eq!(tiles.iter().map(|tile| [tile.width, tile.height]).collect::<Vec<[usize; 2]>>(), vec![[3, 2], [2, 2], [3, 2], [2, 2]]);
+		assert_eq!(tiles[[1, 0]].data, vec![3, 4, 8, 9]);
+		assert_eq!(tiles[[0, 1]].data, vec![10, 11, 12, 15, 16, 17]);
+		assert_eq!(tiles.flatten_grid(), grid);
+	}
+
+	#[test]
+	fn test_split_by() {
+		let grid:Grid<usize> = Grid::new((0..20).collect(), 5, 4);
+		println!("[grid]\n{grid}\n");
+		let tiles:Grid<Grid<usize>> = grid.split_by(&[1, 3], &[3]);
+
+		assert_eq!([tiles.width, tiles.height], [2, 1]);
+		assert_eq!(tiles[0].data, vec![0, 5, 10]);
+		assert_eq!(tiles[1].data, vec![1, 2, 3, 6, 7, 8, 11, 12, 13]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_split_by_overflow() {
+		let grid:Grid<usize> = Grid::new((0..20).collect(), 5, 4);
+		grid.split_by(&[3, 3], &[4]);
+	}
+
+	#[test]
+	fn test_concat_horizontal() {
+		let grids:Vec<Grid<usize>> = vec![
+			Grid::new(vec![1; 4], 2, 2),
+			Grid::new(vec![2; 4], 1, 4),
+			Grid::new(vec![3; 3], 1, 3)
+		];
+		let concatenated:Grid<usize> = Grid::concat_horizontal(&grids, GridAlignment::Center);
+		println!("[concatenated]\n{concatenated}\n");
+
+		assert_eq!([concatenated.width, concatenated.height], [4, 4]);
+		assert_eq!(concatenated.data_2d(), [[0, 0, 2, 3], [1, 1, 2, 3], [1, 1, 2, 3], [0, 0, 2, 0]]);
+		assert_eq!(Grid::concat_horizontal(&grids, GridAlignment::End).data_2d()[0], [0, 0, 2, 0]);
+	}
+
+	#[test]
+	fn test_concat_vertical() {
+		let grids:Vec<Grid<usize>> = vec![
+			Grid::new(vec![1; 2], 1, 2),
+			Grid::new(vec![2; 3], 3, 1)
+		];
+		let concatenated:Grid<usize> = Grid::concat_vertical(&grids, GridAlignment::End);
+		println!("[concatenated]\n{concatenated}\n");
+
+		assert_eq!(concatenated.data_2d(), [[0, 0, 1], [0, 0, 1], [2, 2, 2]]);
+		assert_eq!(Grid::concat_vertical(&grids, GridAlignment::Start).data_2d()[0], [1, 0, 0]);
+		assert!(Grid::<usize>::concat_vertical(&[], GridAlignment::Start).is_empty());
+	}
+
+	#[test]
+	fn test_concat_without_size() {
+		let narrow_grids:Vec<Grid<usize>> = vec![Grid::new(Vec::new(), 0, 3), Grid::new(Vec::new(), 0, 2)];
+		let flat_grids:Vec<Grid<usize>> = vec![Grid::new(Vec::new(), 2, 0), Grid::new(Vec::new(), 3, 0)];
+
+		let horizontal:Grid<usize> = Grid::concat_horizontal(&narrow_grids, GridAlignment::Center);
+		assert!(horizontal.is_empty());
+		assert_eq!([horizontal.width, horizontal.height], [0, 3]);
+		let vertical:Grid<usize> = Grid::concat_vertical(&flat_grids, GridAlignment::Center);
+		assert!(vertical.is_empty());
+		assert_eq!([vertical.width, vertical.height], [3, 0]);
+		assert!(Grid::concat_horizontal(&flat_grids, GridAlignment::Start).is_empty());
+		assert!(Grid::concat_vertical(&narrow_grids, GridAlignment::Start).is_empty());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,232 @@
+use std::cmp::Ordering;
+use crate::{ Grid, GridError, GridNumeric };
+
+
+
+impl<T> Grid<T> where T:GridNumeric {
+
+	/* HELPER METHODS */
+
+	/// Validate that the mask is the same size as self.
+	fn validate_statistics_mask(&self, mask:&Grid<bool>) -> Result<(), GridError> {
+		if [self.width, self.height] != [mask.width, mask.height] {
+			return Err(GridError::SizeMismatch { expected: [self.width, self.height], found: [mask.width, mask.height] });
+		}
+		Ok(())
+	}
+
+	/// Get the index and value of all cells that are positive in the selection. Selects all cells if no selection is given. The selection should be validated to be the same size as self.
+	fn selected_cells<'a>(&'a self, selection:Option<&'a Grid<bool>>) -> Box<dyn Iterator<Item = (usize, T)> + 'a> {
+		match selection {
+			Some(selection) => Box::new(self.data.iter().zip(&selection.data).enumerate().filter(|(_, (_, selected))| **selected).map(|(index, (value, _))| (index, *value))),
+			None => Box::new(self.data.iter().enumerate().map(|(index, value)| (index, *value)))
+		}
+	}
+
+	/// Get the sorted values of all selected cells.
+	fn sorted_selected_values(&self, selection:Option<&Grid<bool>>) -> Vec<T> {
+		let mut values:Vec<T> = self.selected_cells(selection).map(|(_, value)| value).collect();
+		values.sort_by(|left, right| left.partial_cmp(right).unwrap_or(Ordering::Equal));
+		values
+	}
+
+	/// Get the sum of all selected cells.
+	fn selection_sum(&self, selection:Option<&Grid<bool>>) -> f64 {
+		self.selected_cells(selection).map(|(_, value)| value.to_f64()).sum()
+	}
+
+	/// Get the mean of all selected cells.
+	fn selection_mean(&self, selection:Option<&Grid<bool>>) -> Option<f64> {
+		let (sum, count) = self.selected_cells(selection).fold((0.0, 0), |(sum, count), (_, value)| (sum + value.to_f64(), count + 1));
+		if count == 0 { None } else { Some(sum / count as f64) }
+	}
+
+	/// Get the first selected cell that is preferred over all others by the given ordering.
+	fn selection_extreme(&self, selection:Option<&Grid<bool>>, preferred:Ordering) -> Option<(T, [usize; 2])> {
+		let mut extreme:Option<(usize, T)> = None;
+		for (index, value) in self.selected_cells(selection) {
+			match extreme {
+				Some((_, extreme_value)) if value.partial_cmp(&extreme_value) != Some(preferred) => {},
+				_ => extreme = Some((index, value))
+			}
+		}
+		extreme.map(|(index, value)| (value, self.index_to_xy(index)))
+	}
+
+	/// Get the population variance of all selected cells.
+	fn selection_variance(&self, selection:Option<&Grid<bool>>) -> Option<f64> {
+		let mean:f64 = self.selection_mean(selection)?;
+		let (squared_difference_sum, count) = self.selected_cells(selection).fold((0.0, 0), |(sum, count), (_, value)| (sum + (value.to_f64() - mean).powi(2), count + 1));
+		Some(squared_difference_sum / count as f64)
+	}
+
+	/// Get the percentile of all selected cells.
+	fn selection_percentile(&self, selection:Option<&Grid<bool>>, percentile:f64) -> Option<f64> {
+		let values:Vec<T> = self.sorted_selected_values(selection);
+		if values.is_empty() {
+			return None;
+		}
+
+		// Interpolate between the two closest ranks.
+		let rank:f64 = percentile.clamp(0.0, 100.0) / 100.0 * (values.len() - 1) as f64;
+		let lower:usize = rank.floor() as usize;
+		let upper:usize = rank.ceil() as usize;
+		let lower_value:f64 = values[lower].to_f64();
+		Some(lower_value + (values[upper].to_f64() - lower_value) * (rank - lower as f64))
+	}
+
+	/// Get a histogram of all selected cells.
+	fn selection_histogram(&self, selection:Option<&Grid<bool>>, bins:usize) -> Vec<usize> {
+		let mut histogram:Vec<usize> = vec![0; bins];
+		let (Some((min, _)), Some((max, _))) = (self.selection_extreme(selection, Ordering::Less), self.selection_extreme(selection, Ordering::Greater)) else {
+			return histogram;
+		};
+		if bins == 0 {
+			return histogram;
+		}
+
+		// Count values in equally sized bins between the min and max value.
+		let min:f64 = min.to_f64();
+		let range:f64 = max.to_f64() - min;
+		for (_, value) in self.selected_cells(selection) {
+			let bin:usize = if range > 0.0 { ((value.to_f64() - min) / range * bins as f64) as usize } else { 0 };
+			histogram[bin.min(bins - 1)] += 1;
+		}
+		histogram
+	}
+
+
+
+	/* STATISTICS METHODS */
+
+	/// Get the sum of all values.
+	pub fn sum(&self) -> f64 {
+		self.selection_sum(None)
+	}
+
+	/// Get the mean of all values. Returns None if the grid is empty.
+	pub fn mean(&self) -> Option<f64> {
+		self.selection_mean(None)
+	}
+
+	/// Get the lowest value.
+	pub fn min(&self) -> Option<T> {
+		self.min_with_position().map(|(value, _)| value)
+	}
+
+	/// Get the highest value.
+	pub fn max(&self) -> Option<T> {
+		self.max_with_position().map(|(value, _)| value)
+	}
+
+	/// Get the lowest value and the position of its first occurrence.
+	pub fn min_with_position(&self) -> Option<(T, [usize; 2])> {
+		self.selection_extreme(None, Ordering::Less)
+	}
+
+	/// Get the highest value and the position of its first occurrence.
+	pub fn max_with_position(&self) -> Option<(T, [usize; 2])> {
+		self.selection_extreme(None, Ordering::Greater)
+	}
+
+	/// Get the position of the first occurrence of the lowest value.
+	pub fn argmin(&self) -> Option<[usize; 2]> {
+		self.min_with_position().map(|(_, position)| position)
+	}
+
+	/// Get the position of the first occurrence of the highest value.
+	pub fn argmax(&self) -> Option<[usize; 2]> {
+		self.max_with_position().map(|(_, position)| position)
+	}
+
+	/// Get the population variance of all values.
+	pub fn variance(&self) -> Option<f64> {
+		self.selection_variance(None)
+	}
+
+	/// Get the value at the given percentile, between 0.0 and 100.0. Interpolates between the two closest values.
+	pub fn percentile(&self, percentile:f64) -> Option<f64> {
+		self.selection_percentile(None, percentile)
+	}
+
+	/// Get the median of all values.
+	pub fn median(&self) -> Option<f64> {
+		self.percentile(50.0)
+	}
+
+	/// Count the values in the given amount of equally sized bins between the lowest and highest value.
+	pub fn histogram(&self, bins:usize) -> Vec<usize> {
+		self.selection_histogram(None, bins)
+	}
+
+
+
+	/* MASKED STATISTICS METHODS */
+
+	/// Get the sum of all values in the positive cells of the mask. Returns an error if the mask is not the same size as self.
+	pub fn sum_masked<U>(&self, mask:&U) -> Result<f64, GridError> where U:AsRef<Grid<bool>> {
+		self.validate_statistics_mask(mask.as_ref())?;
+		Ok(self.selection_sum(Some(mask.as_ref())))
+	}
+
+	/// Get the mean of all values in the positive cells of the mask. Returns None if the mask has no positive cells. Returns an error if the mask is not the same size as self.
+	pub fn mean_masked<U>(&self, mask:&U) -> Result<Option<f64>, GridError> where U:AsRef<Grid<bool>> {
+		self.validate_statistics_mask(mask.as_ref())?;
+		Ok(self.selection_mean(Some(mask.as_ref())))
+	}
+
+	/// Get the lowest value in the positive cells of the mask. Returns an error if the mask is not the same size as self.
+	pub fn min_masked<U>(&self, mask:&U) -> Result<Option<T>, GridError> where U:AsRef<Grid<bool>> {
+		Ok(self.min_with_position_masked(mask)?.map(|(value, _)| value))
+	}
+
+	/// Get the highest value in the positive cells of the mask. Returns an error if the mask is not the same size as self.
+	pub fn max_masked<U>(&self, mask:&U) -> Result<Option<T>, GridError> where U:AsRef<Grid<bool>> {
+		Ok(self.max_with_position_masked(mask)?.map(|(value, _)| value))
+	}
+
+	/// Get the lowest value in the positive cells of the mask and the position of its first occurrence. Returns an error if the mask is not the same size as self.
+	pub fn min_with_position_masked<U>(&self, mask:&U) -> Result<Option<(T, [usize; 2])>, GridError> where U:AsRef<Grid<bool>> {
+		self.validate_statistics_mask(mask.as_ref())?;
+		Ok(self.selection_extreme(Some(mask.as_ref()), Ordering::Less))
+	}
+
+	/// Get the highest value in the positive cells of the mask and the position of its first occurrence. Returns an error if the mask is not the same size as self.
+	pub fn max_with_position_masked<U>(&self, mask:&U) -> Result<Option<(T, [usize; 2])>, GridError> where U:AsRef<Grid<bool>> {
+		self.validate_statistics_mask(mask.as_ref())?;
+		Ok(self.selection_extreme(Some(mask.as_ref()), Ordering::Greater))
+	}
+
+	/// Get the position of the first occurrence of the lowest value in the positive cells of the mask. Returns an error if the mask is not the same size as self.
+	pub fn argmin_masked<U>(&self, mask:&U) -> Result<Option<[usize; 2]>, GridError> where U:AsRef<Grid<bool>> {
+		Ok(self.min_with_position_masked(mask)?.map(|(_, position)| position))
+	}
+
+	/// Get the position of the first occurrence of the highest value in the positive cells of the mask. Returns an error if the mask is not the same size as self.
+	pub fn argmax_masked<U>(&self, mask:&U) -> Result<Option<[usize; 2]>, GridError> where U:AsRef<Grid<bool>> {
+		Ok(self.max_with_position_masked(mask)?.map(|(_, position)| position))
+	}
+
+	/// Get the population variance of all values in the positive cells of the mask. Returns an error if the mask is not the same size as self.
+	pub fn variance_masked<U>(&self, mask:&U) -> Result<Option<f64>, GridError> where U:AsRef<Grid<bool>> {
+		self.validate_statistics_mask(mask.as_ref())?;
+		Ok(self.selection_variance(Some(mask.as_ref())))
+	}
+
+	/// Get the value at the given percentile, between 0.0 and 100.0, of the values in the positive cells of the mask. Returns an error if the mask is not the same size as self.
+	pub fn percentile_masked<U>(&self, mask:&U, percentile:f64) -> Result<Option<f64>, GridError> where U:AsRef<Grid<bool>> {
+		self.validate_statistics_mask(mask.as_ref())?;
+		Ok(self.selection_percentile(Some(mask.as_ref()), percentile))
+	}
+
+	/// Get the median of all values in the positive cells of the mask. Returns an error if the mask is not the same size as self.
+	pub fn median_masked<U>(&self, mask:&U) -> Result<Option<f64>, GridError> where U:AsRef<Grid<bool>> {
+		self.percentile_masked(mask, 50.0)
+	}
+
+	/// Count the values in the positive cells of the mask in the given amount of equally sized bins between the lowest and highest value. Returns an error if the mask is not the same size as self.
+	pub fn histogram_masked<U>(&self, mask:&U, bins:usize) -> Result<Vec<usize>, GridError> where U:AsRef<Grid<bool>> {
+		self.validate_statistics_mask(mask.as_ref())?;
+		Ok(self.selection_histogram(Some(mask.as_ref()), bins))
+	}
+}
\ No newline at end of file
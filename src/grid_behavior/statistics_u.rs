@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Grid, GridError, GridMask, GridRegion };
+
+
+
+	#[test]
+	fn test_basic_statistics() {
+		let grid:Grid<i32> = Grid::new(vec![
+			4, 8, 1,
+			9, 2, 6,
+			3, 7, 5
+		], 3, 3);
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(grid.sum(), 45.0);
+		assert_eq!(grid.mean(), Some(5.0));
+		assert_eq!(grid.min(), Some(1));
+		assert_eq!(grid.max(), Some(9));
+		assert_eq!(grid.min_with_position(), Some((1, [2, 0])));
+		assert_eq!(grid.max_with_position(), Some((9, [0, 1])));
+		assert_eq!(grid.argmin(), Some([2, 0]));
+		assert_eq!(grid.argmax(), Some([0, 1]));
+		assert!((grid.variance().unwrap() - 60.0 / 9.0).abs() < 1e-9);
+		assert_eq!(grid.median(), Some(5.0));
+		assert_eq!(grid.percentile(0.0), Some(1.0));
+		assert_eq!(grid.percentile(100.0), Some(9.0));
+		assert_eq!(grid.percentile(12.5), Some(2.0));
+		assert_eq!(grid.histogram(4), vec![2, 2, 2, 3]);
+	}
+
+	#[test]
+	fn test_empty_statistics() {
+		let grid:Grid<f32> = Grid::new(Vec::new(), 0, 0);
+
+		assert_eq!(grid.sum(), 0.0);
+		assert_eq!(grid.mean(), None);
+		assert_eq!(grid.argmax(), None);
+		assert_eq!(grid.median(), None);
+		assert_eq!(grid.histogram(3), vec![0, 0, 0]);
+		assert_eq!(Grid::new(vec![2.0; 4], 2, 2).histogram(2), vec![4, 0]);
+	}
+
+	#[test]
+	fn test_masked_statistics() {
+		let grid:Grid<u8> = Grid::new(vec![
+			10, 20, 30,
+			40, 50, 60
+		], 3, 2);
+		let mask:GridMask = GridMask::new(Grid::new(vec![false, true, true, false, true, false], 3, 2));
+		let region:GridRegion = GridRegion::new(mask.grid().clone());
+
+		assert_eq!(grid.sum_masked(&mask).unwrap(), 100.0);
+		assert_eq!(grid.mean_masked(&region).unwrap(), Some(100.0 / 3.0));
+		assert_eq!(grid.min_with_position_masked(&mask).unwrap(), Some((20, [1, 0])));
+		assert_eq!(grid.argmax_masked(&region).unwrap(), Some([1, 1]));
+		assert_eq!(grid.median_masked(&mask).unwrap(), Some(30.0));
+		assert_eq!(grid.histogram_masked(&region, 3).unwrap(), vec![1, 1, 1]);
+		assert_eq!(grid.mean_masked(&GridMask::new(Grid::new(vec![false; 6], 3, 2))).unwrap(), None);
+	}
+
+	#[test]
+	fn test_masked_statistics_size_mismatch() {
+		let grid:Grid<u8> = Grid::new(vec![0; 6], 3, 2);
+		let mask:GridMask = GridMask::new(Grid::new(vec![true; 4], 2, 2));
+
+		assert!(matches!(grid.sum_masked(&mask), Err(GridError::SizeMismatch { expected: [3, 2], found: [2, 2] })));
+		assert!(matches!(grid.mean_masked(&mask), Err(GridError::SizeMismatch { .. })));
+		assert!(matches!(grid.min_masked(&mask), Err(GridError::SizeMismatch { .. })));
+		assert!(matches!(grid.argmax_masked(&mask), Err(GridError::SizeMismatch { .. })));
+		assert!(matches!(grid.median_masked(&mask), Err(GridError::SizeMismatch { .. })));
+		assert!(matches!(grid.histogram_masked(&mask, 2), Err(GridError::SizeMismatch { .. })));
+		assert_eq!(grid.mean_masked(&Grid::new(vec![true; 6], 3, 2)).unwrap(), Some(0.0));
+	}
+}
\ No newline at end of file
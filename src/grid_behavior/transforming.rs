@@ -0,0 +1,146 @@
+use crate::Grid;
+
+
+
+impl<T> Grid<T> {
+
+	/* IN-PLACE METHODS */
+
+	/// Rotate the grid 90 degrees clockwise.
+	pub fn rotate_90(&mut self) {
+		*self = std::mem::take(self).into_rotated_90();
+	}
+
+	/// Rotate the grid 180 degrees.
+	pub fn rotate_180(&mut self) {
+		self.data.reverse();
+	}
+
+	/// Rotate the grid 270 degrees clockwise, which is 90 degrees counter-clockwise.
+	pub fn rotate_270(&mut self) {
+		*self = std::mem::take(self).into_rotated_270();
+	}
+
+	/// Mirror the grid horizontally, swapping the left and right side.
+	pub fn flip_horizontal(&mut self) {
+		if self.width > 0 {
+			for row in self.data.chunks_mut(self.width) {
+				row.reverse();
+			}
+		}
+	}
+
+	/// Mirror the grid vertically, swapping the top and bottom side.
+	pub fn flip_vertical(&mut self) {
+		let width:usize = self.width;
+		for row_index in 0..self.height / 2 {
+			let (top, bottom) = self.data.split_at_mut((self.height - row_index - 1) * width);
+			top[row_index * width..(row_index + 1) * width].swap_with_slice(&mut bottom[..width]);
+		}
+	}
+
+	/// Transpose the grid, swapping the X and Y axis.
+	pub fn transpose(&mut self) {
+		*self = std::mem::take(self).into_transposed();
+	}
+
+
+
+	/* CONSUMING METHODS */
+
+	/// Return self rotated 90 degrees clockwise.
+	pub fn into_rotated_90(self) -> Grid<T> {
+		let source_width:usize = self.width;
+		let source_height:usize = self.height;
+		self.into_remapped(source_height, source_width, |x, y| (source_height - 1 - x) * source_width + y)
+	}
+
+	/// Return self rotated 180 degrees.
+	pub fn into_rotated_180(mut self) -> Grid<T> {
+		self.rotate_180();
+		self
+	}
+
+	/// Return self rotated 270 degrees clockwise, which is 90 degrees counter-clockwise.
+	pub fn into_rotated_270(self) -> Grid<T> {
+		let source_width:usize = self.width;
+		let source_height:usize = self.height;
+		self.into_remapped(source_height, source_width, |x, y| x * source_width + source_width - 1 - y)
+	}
+
+	/// Return self mirrored horizontally.
+	pub fn into_flipped_horizontal(mut self) -> Grid<T> {
+		self.flip_horizontal();
+		self
+	}
+
+	/// Return self mirrored vertically.
+	pub fn into_flipped_vertical(mut self) -> Grid<T> {
+		self.flip_vertical();
+		self
+	}
+
+	/// Return self transposed.
+	pub fn into_transposed(self) -> Grid<T> {
+		let source_width:usize = self.width;
+		let source_height:usize = self.height;
+		self.into_remapped(source_height, source_width, |x, y| x * source_width + y)
+	}
+
+	/// Move all values into a new grid of the given size. The remap function takes the X and Y coordinate in the new grid and returns the index of the value in self.
+	fn into_remapped<U>(self, width:usize, height:usize, source_index_function:U) -> Grid<T> where U:Fn(usize, usize) -> usize {
+		let mut source_data:Vec<Option<T>> = self.data.into_iter().map(Some).collect();
+		let mut data:Vec<T> = Vec::with_capacity(width * height);
+		for y in 0..height {
+			for x in 0..width {
+				data.push(source_data[source_index_function(x, y)].take().expect("Remapping function used the same source value twice."));
+			}
+		}
+		Grid::new(data, width, height)
+	}
+}
+impl<T> Grid<T> where T:Clone {
+
+	/* BORROWING METHODS */
+
+	/// Get a copy of self rotated 90 degrees clockwise.
+	pub fn rotated_90(&self) -> Grid<T> {
+		self.remapped(self.height, self.width, |x, y| (self.height - 1 - x) * self.width + y)
+	}
+
+	/// Get a copy of self rotated 180 degrees.
+	pub fn rotated_180(&self) -> Grid<T> {
+		Grid::new(self.data.iter().rev().cloned().collect(), self.width, self.height)
+	}
+
+	/// Get a copy of self rotated 270 degrees clockwise, which is 90 degrees counter-clockwise.
+	pub fn rotated_270(&self) -> Grid<T> {
+		self.remapped(self.height, self.width, |x, y| x * self.width + self.width - 1 - y)
+	}
+
+	/// Get a copy of self mirrored horizontally.
+	pub fn flipped_horizontal(&self) -> Grid<T> {
+		self.remapped(self.width, self.height, |x, y| y * self.width + self.width - 1 - x)
+	}
+
+	/// Get a copy of self mirrored vertically.
+	pub fn flipped_vertical(&self) -> Grid<T> {
+		self.remapped(self.width, self.height, |x, y| (self.height - 1 - y) * self.width + x)
+	}
+
+	/// Get a transposed copy of self.
+	pub fn transposed(&self) -> Grid<T> {
+		self.remapped(self.height, self.width, |x, y| x * self.width + y)
+	}
+
+	/// Clone all values into a new grid of the given size. The remap function takes the X and Y coordinate in the new grid and returns the index of the value in self.
+	fn remapped<U>(&self, width:usize, height:usize, source_index_function:U) -> Grid<T> where U:Fn(usize, usize) -> usize {
+		let mut data:Vec<T> = Vec::with_capacity(width * height);
+		for y in 0..height {
+			for x in 0..width {
+				data.push(self.data[source_index_function(x, y)].clone());
+			}
+		}
+		Grid::new(data, width, height)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+	use crate::Grid;
+
+
+
+	fn sample_grid() -> Grid<i32> {
+		Grid::new(vec![0, 1, 2, 3, 4, 5], 3, 2)
+	}
+
+
+
+	#[test]
+	fn test_rotate_90() {
+		let grid:Grid<i32> = sample_grid();
+		println!("[grid]\n{grid}\n");
+		let rotated:Grid<i32> = grid.rotated_90();
+		println!("[rotated]\n{rotated}\n");
+
+		assert_eq!([rotated.width, rotated.height], [2, 3]);
+		assert_eq!(rotated.data_2d(), [[3, 0], [4, 1], [5, 2]]);
+		assert_eq!(grid.clone().into_rotated_90(), rotated);
+		let mut in_place:Grid<i32> = grid.clone();
+		in_place.rotate_90();
+		assert_eq!(in_place, rotated);
+		assert_eq!([in_place.width, in_place.height], [2, 3]);
+	}
+
+	#[test]
+	fn test_rotate_180() {
+		let grid:Grid<i32> = sample_grid();
+		let rotated:Grid<i32> = grid.rotated_180();
+		println!("[rotated]\n{rotated}\n");
+
+		assert_eq!([rotated.width, rotated.height], [3, 2]);
+		assert_eq!(rotated.data_2d(), [[5, 4, 3], [2, 1, 0]]);
+		assert_eq!(grid.clone().into_rotated_180(), rotated);
+		assert_eq!(grid.rotated_90().rotated_90(), rotated);
+	}
+
+	#[test]
+	fn test_rotate_270() {
+		let grid:Grid<i32> = sample_grid();
+		let rotated:Grid<i32> = grid.rotated_270();
+		println!("[rotated]\n{rotated}\n");
+
+		assert_eq!([rotated.width, rotated.height], [2, 3]);
+		assert_eq!(rotated.data_2d(), [[2, 5], [1, 4], [0, 3]]);
+		assert_eq!(grid.clone().into_rotated_270(), rotated);
+		let mut in_place:Grid<i32> = grid.clone();
+		in_place.rotate_270();
+		in_place.rotate_90();
+		assert_eq!(in_place, grid);
+	}
+
+	#[test]
+	fn test_flip() {
+		let grid:Grid<i32> = sample_grid();
+		let flipped_horizontal:Grid<i32> = grid.flipped_horizontal();
+		let flipped_vertical:Grid<i32> = grid.flipped_vertical();
+		println!("[flipped horizontal]\n{flipped_horizontal}\n");
+		println!("[flipped vertical]\n{flipped_vertical}\n");
+
+		assert_eq!(flipped_horizontal.data_2d(), [[2, 1, 0], [5, 4, 3]]);
+		assert_eq!(flipped_vertical.data_2d(), [[3, 4, 5], [0, 1, 2]]);
+		assert_eq!(grid.clone().into_flipped_horizontal(), flipped_horizontal);
+		assert_eq!(grid.clone().into_flipped_vertical(), flipped_vertical);
+
+		let odd_grid:Grid<i32> = Grid::new((0..9).collect(), 3, 3);
+		let mut in_place:Grid<i32> = odd_grid.clone();
+		in_place.flip_vertical();
+		assert_eq!(in_place.data_2d(), [[6, 7, 8], [3, 4, 5], [0, 1, 2]]);
+		in_place.flip_vertical();
+		assert_eq!(in_place, odd_grid);
+	}
+
+	#[test]
+	fn test_transpose() {
+		let grid:Grid<i32> = sample_grid();
+		let transposed:Grid<i32> = grid.transposed();
+		println!("[transposed]\n{transposed}\n");
+
+		assert_eq!([transposed.width, transposed.height], [2, 3]);
+		assert_eq!(transposed.data_2d(), [[0, 3], [1, 4], [2, 5]]);
+		let mut in_place:Grid<i32> = grid.clone();
+		in_place.transpose();
+		assert_eq!(in_place, transposed);
+		in_place.transpose();
+		assert_eq!(in_place, grid);
+	}
+
+	#[test]
+	fn test_transform_without_clone() {
+		struct NoClone(i32);
+		let grid:Grid<NoClone> = Grid::new((0..6).map(NoClone).collect(), 3, 2);
+		let rotated:Grid<NoClone> = grid.into_rotated_90();
+
+		assert_eq!(rotated.iter().map(|value| value.0).collect::<Vec<i32>>(), vec![3, 0, 4, 1, 5, 2]);
+	}
+}
\ No newline at end of file
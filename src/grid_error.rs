@@ -0,0 +1,60 @@
+use std::{ error::Error, fmt::{ Display, Formatter } };
+
+
+
+#[derive(Debug)]
+pub enum GridError {
+	SizeMismatch { expected:[usize; 2], found:[usize; 2] }, // Two grids, or a grid and a mask, that should have the same width and height do not.
+	DataLengthMismatch { expected:usize, found:usize }, // The data of a grid does not contain exactly width * height values.
+	SubGridTooLarge { grid:[usize; 2], sub_grid:[usize; 2] }, // A sub-grid is searched for in a grid that is smaller than the sub-grid.
+	OutOfBounds { position:[isize; 2], size:[usize; 2] }, // A position falls outside of the grid.
+	OutsideOfRegion { position:[usize; 2] }, // A position falls outside of the positive pixels of a region.
+	UnreachablePath, // No path exists between the requested positions.
+	Parse(String), // Data could not be parsed in the expected format.
+	FileNotFound(String), // The file at the given path does not exist.
+	External(Box<dyn Error + Send + Sync>) // An error originating from outside of this crate, like file access.
+}
+impl Display for GridError {
+	fn fmt(&self, f:&mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			GridError::SizeMismatch { expected, found } => write!(f, "Expected a size of {}x{}, found {}x{}.", expected[0], expected[1], found[0], found[1]),
+			GridError::DataLengthMismatch { expected, found } => write!(f, "Expected {expected} values of grid data, found {found}."),
+			GridError::SubGridTooLarge { grid, sub_grid } => write!(f, "Cannot find sub-grid of {}x{} in smaller grid of {}x{}.", sub_grid[0], sub_grid[1], grid[0], grid[1]),
+			GridError::OutOfBounds { position, size } => write!(f, "Position [{}, {}] falls outside of grid of {}x{}.", position[0], position[1], size[0], size[1]),
+			GridError::OutsideOfRegion { position } => write!(f, "Position [{}, {}] falls outside of the region.", position[0], position[1]),
+			GridError::UnreachablePath => write!(f, "Could not find path."),
+			GridError::Parse(message) => write!(f, "Could not parse data: {message}"),
+			GridError::FileNotFound(path) => write!(f, "File '{path}' does not exist."),
+			GridError::External(error) => write!(f, "{error}")
+		}
+	}
+}
+impl Error for GridError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			GridError::External(error) => Some(error.as_ref()),
+			_ => None
+		}
+	}
+}
+impl From<Box<dyn Error + Send + Sync>> for GridError {
+	fn from(error:Box<dyn Error + Send + Sync>) -> Self {
+		GridError::External(error)
+	}
+}
+impl From<Box<dyn Error>> for GridError {
+	fn from(error:Box<dyn Error>) -> Self {
+		GridError::External(error.to_string().into()) // Keep only the message, as the error itself cannot be sent between threads.
+	}
+}
+impl From<std::io::Error> for GridError {
+	fn from(error:std::io::Error) -> Self {
+		GridError::External(Box::new(error))
+	}
+}
+#[cfg(feature = "png_conversion")]
+impl From<image::ImageError> for GridError {
+	fn from(error:image::ImageError) -> Self {
+		GridError::External(Box::new(error))
+	}
+}
\ No newline at end of file
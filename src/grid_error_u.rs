@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+	use crate::GridError;
+	use std::error::Error;
+
+
+
+	fn assert_send_sync<T:Send + Sync>() {}
+
+
+
+	#[test]
+	fn test_grid_error_is_send_sync() {
+		assert_send_sync::<GridError>();
+	}
+
+	#[test]
+	fn test_external_error_conversion() {
+		let local_error:Box<dyn Error> = "local failure".into();
+		let error:GridError = local_error.into();
+		println!("[error]\n{error}\n");
+
+		assert!(matches!(error, GridError::External(_)));
+		assert_eq!(error.to_string(), "local failure");
+		assert!(std::thread::spawn(move || error.to_string()).join().is_ok());
+	}
+}
\ No newline at end of file
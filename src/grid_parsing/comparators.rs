@@ -0,0 +1,102 @@
+use crate::{ Color, ColorChannel };
+
+
+
+pub trait CellComparator<T> {
+
+	/// Whether or not the two cells are considered a match.
+	fn cells_match(&self, left:&T, right:&T) -> bool;
+}
+impl<T, U> CellComparator<T> for U where U:Fn(&T, &T) -> bool {
+	fn cells_match(&self, left:&T, right:&T) -> bool {
+		self(left, right)
+	}
+}
+
+
+
+pub struct DistanceComparator<U> {
+	distance:U,
+	max_distance:f64
+}
+impl<U> DistanceComparator<U> {
+
+	/// Create a new comparator that considers two cells a match when the distance between them does not exceed the max distance.
+	pub fn new(distance:U, max_distance:f64) -> DistanceComparator<U> {
+		DistanceComparator { distance, max_distance }
+	}
+
+	/// Get the max distance at which two cells are still considered a match.
+	pub fn max_distance(&self) -> f64 {
+		self.max_distance
+	}
+}
+impl<T, U> CellComparator<T> for DistanceComparator<U> where U:Fn(&T, &T) -> f64 {
+	fn cells_match(&self, left:&T, right:&T) -> bool {
+		(self.distance)(left, right) <= self.max_distance
+	}
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ColorTolerance {
+	tolerances:[u8; 4], // Max difference per channel, in the order alpha, red, green, blue.
+	ignore_alpha:bool
+}
+impl ColorTolerance {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new color comparator that allows the given difference in every channel.
+	pub fn new(tolerance:u8) -> ColorTolerance {
+		ColorTolerance::per_channel([tolerance; 4])
+	}
+
+	/// Create a new color comparator with a separate allowed difference for each channel. Channels are in the order alpha, red, green, blue.
+	pub fn per_channel(tolerances:[u8; 4]) -> ColorTolerance {
+		ColorTolerance { tolerances, ignore_alpha: false }
+	}
+
+	/// Return self with the given allowed difference for a single channel.
+	pub fn with_channel_tolerance(mut self, channel:ColorChannel, tolerance:u8) -> Self {
+		self.tolerances[ColorChannel::ALL.iter().position(|candidate| *candidate == channel).unwrap()] = tolerance;
+		self
+	}
+
+	/// Return self ignoring the alpha channel entirely.
+	pub fn ignoring_alpha(mut self) -> Self {
+		self.ignore_alpha = true;
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the allowed difference of the given channel.
+	pub fn tolerance_of(&self, channel:ColorChannel) -> u8 {
+		self.tolerances[ColorChannel::ALL.iter().position(|candidate| *candidate == channel).unwrap()]
+	}
+
+	/// Whether or not the alpha channel is ignored.
+	pub fn ignores_alpha(&self) -> bool {
+		self.ignore_alpha
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Whether or not the two colors are within tolerance of each other.
+	pub fn matches(&self, left:&Color, right:&Color) -> bool {
+		ColorChannel::ALL.iter().zip(&self.tolerances).all(|(channel, tolerance)| {
+			(self.ignore_alpha && *channel == ColorChannel::Alpha) || channel.value_of(left).abs_diff(channel.value_of(right)) <= *tolerance
+		})
+	}
+}
+impl CellComparator<Color> for ColorTolerance {
+	fn cells_match(&self, left:&Color, right:&Color) -> bool {
+		self.matches(left, right)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod test {
+	use crate::{ CellComparator, Color, ColorChannel, ColorTolerance, DistanceComparator };
+
+
+
+	#[test]
+	fn test_closure_comparator() {
+		let comparator = |left:&i32, right:&i32| left % 10 == right % 10;
+		assert!(comparator.cells_match(&3, &13));
+		assert!(!comparator.cells_match(&3, &14));
+	}
+
+	#[test]
+	fn test_distance_comparator() {
+		let comparator = DistanceComparator::new(|left:&f32, right:&f32| (left - right).abs() as f64, 0.5);
+		assert_eq!(comparator.max_distance(), 0.5);
+		assert!(comparator.cells_match(&1.0, &1.5));
+		assert!(!comparator.cells_match(&1.0, &1.6));
+	}
+
+	#[test]
+	fn test_color_tolerance() {
+		let base:Color = Color(0xFF804020);
+		let noisy:Color = Color(0xF0834322);
+		println!("[colors]\n{base} {noisy}\n");
+
+		assert!(!ColorTolerance::new(2).matches(&base, &noisy));
+		assert!(!ColorTolerance::new(3).matches(&base, &noisy));
+		assert!(ColorTolerance::new(3).ignoring_alpha().matches(&base, &noisy));
+		assert!(ColorTolerance::new(15).matches(&base, &noisy));
+		assert!(!ColorTolerance::per_channel([15, 3, 2, 3]).matches(&base, &noisy));
+		assert!(ColorTolerance::per_channel([15, 3, 2, 3]).with_channel_tolerance(ColorChannel::Green, 3).cells_match(&base, &noisy));
+		assert_eq!(ColorTolerance::per_channel([1, 2, 3, 4]).tolerance_of(ColorChannel::Blue), 4);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,189 @@
+use crate::{ Grid, GridRegion };
+
+
+
+const EUCLIDEAN_INFINITY:f64 = 1.0e20;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DistanceTarget {
+	Outside, // Measure the distance from each positive pixel to the nearest pixel outside of the region. Positions beyond the edge of the grid count as outside.
+	Inside // Measure the distance from each negative pixel to the nearest pixel inside of the region.
+}
+
+
+
+impl GridRegion {
+
+	/* DISTANCE MAP METHODS */
+
+	/// Map each pixel to its exact euclidean distance to the target. Pixels that are the target themselves have a distance of 0. If there is no target at all, all distances are infinite.
+	pub fn euclidean_distance_map(&self, target:DistanceTarget) -> Grid<f32> {
+		let (features, padding) = self.distance_features(target);
+
+		// Transform columns, then rows, of the squared distances.
+		let mut squared_distances:Grid<f64> = features.map(|is_feature| if is_feature { 0.0 } else { EUCLIDEAN_INFINITY });
+		let mut column:Vec<f64> = vec![0.0; squared_distances.height];
+		for x in 0..squared_distances.width {
+			for (y, value) in column.iter_mut().enumerate() {
+				*value = squared_distances.data[y * squared_distances.width + x];
+			}
+			for (y, value) in Self::squared_distance_transform_1d(&column).into_iter().enumerate() {
+				squared_distances.data[y * squared_distances.width + x] = value;
+			}
+		}
+		if squared_distances.width > 0 {
+			for row in squared_distances.data.chunks_mut(squared_distances.width) {
+				let transformed_row:Vec<f64> = Self::squared_distance_transform_1d(row);
+				row.copy_from_slice(&transformed_row);
+			}
+		}
+
+		// Remove padding and convert to actual distances.
+		Self::remove_distance_padding(squared_distances, padding).map(|squared_distance| if squared_distance >= EUCLIDEAN_INFINITY { f32::INFINITY } else { squared_distance.sqrt() as f32 })
+	}
+
+	/// Map each pixel to its chamfer 3-4 distance to the target. Orthogonal steps cost 3 and diagonal steps cost 4, which approaches three times the euclidean distance. Pixels that are the target themselves have a distance of 0. If there is no target at all, all distances are usize::MAX.
+	pub fn chamfer_distance_map(&self, target:DistanceTarget) -> Grid<usize> {
+		self.weighed_distance_map(target, 3, 4)
+	}
+
+	/// Map each pixel to its chebyshev distance to the target, where diagonal steps cost the same as orthogonal steps. Pixels that are the target themselves have a distance of 0. If there is no target at all, all distances are usize::MAX.
+	pub fn chebyshev_distance_map(&self, target:DistanceTarget) -> Grid<usize> {
+		self.weighed_distance_map(target, 1, 1)
+	}
+
+	/// Map each pixel to its manhattan distance to the target, only taking orthogonal steps. Pixels that are the target themselves have a distance of 0. If there is no target at all, all distances are usize::MAX.
+	pub fn manhattan_distance_map(&self, target:DistanceTarget) -> Grid<usize> {
+		self.weighed_distance_map(target, 1, 2)
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Get a grid of pixels that have a distance of 0 to the target, and the amount of padding added around the region.
+	fn distance_features(&self, target:DistanceTarget) -> (Grid<bool>, usize) {
+		match target {
+			DistanceTarget::Inside => (self.grid.clone(), 0),
+			DistanceTarget::Outside => {
+				let padded_width:usize = self.grid.width + 2;
+				let padded_height:usize = self.grid.height + 2;
+				let mut features:Grid<bool> = Grid::new(vec![true; padded_width * padded_height], padded_width, padded_height);
+				for (y, row) in self.grid.data_2d().into_iter().enumerate() {
+					let row_start:usize = (y + 1) * padded_width + 1;
+					for (feature, value) in features.data[row_start..row_start + row.len()].iter_mut().zip(row) {
+						*feature = !*value;
+					}
+				}
+				(features, 1)
+			}
+		}
+	}
+
+	/// Remove the padding added by `distance_features`.
+	fn remove_distance_padding<T>(grid:Grid<T>, padding:usize) -> Grid<T> {
+		if padding == 0 {
+			grid
+		} else {
+			let bounds:[usize; 4] = [padding, padding, grid.width - 2 * padding, grid.height - 2 * padding];
+			grid.take(bounds)
+		}
+	}
+
+	/// Calculate a distance map using a forward and backward pass, where orthogonal and diagonal steps have a fixed weight.
+	fn weighed_distance_map(&self, target:DistanceTarget, orthogonal_weight:usize, diagonal_weight:usize) -> Grid<usize> {
+		let (features, padding) = self.distance_features(target);
+		let width:usize = features.width;
+		let height:usize = features.height;
+		let mut distances:Grid<usize> = features.map(|is_feature| if is_feature { 0 } else { usize::MAX });
+
+		// Forward pass, from top-left to bottom-right.
+		for y in 0..height {
+			for x in 0..width {
+				let index:usize = y * width + x;
+				let mut distance:usize = distances.data[index];
+				if x > 0 {
+					distance = distance.min(distances.data[index - 1].saturating_add(orthogonal_weight));
+				}
+				if y > 0 {
+					distance = distance.min(distances.data[index - width].saturating_add(orthogonal_weight));
+					if x > 0 {
+						distance = distance.min(distances.data[index - width - 1].saturating_add(diagonal_weight));
+					}
+					if x + 1 < width {
+						distance = distance.min(distances.data[index - width + 1].saturating_add(diagonal_weight));
+					}
+				}
+				distances.data[index] = distance;
+			}
+		}
+
+		// Backward pass, from bottom-right to top-left.
+		for y in (0..height).rev() {
+			for x in (0..width).rev() {
+				let index:usize = y * width + x;
+				let mut distance:usize = distances.data[index];
+				if x + 1 < width {
+					distance = distance.min(distances.data[index + 1].saturating_add(orthogonal_weight));
+				}
+				if y + 1 < height {
+					distance = distance.min(distances.data[index + width].saturating_add(orthogonal_weight));
+					if x + 1 < width {
+						distance = distance.min(distances.data[index + width + 1].saturating_add(diagonal_weight));
+					}
+					if x > 0 {
+						distance = distance.min(distances.data[index + width - 1].saturating_add(diagonal_weight));
+					}
+				}
+				distances.data[index] = distance;
+			}
+		}
+
+		Self::remove_distance_padding(distances, padding)
+	}
+
+	/// Calculate the exact squared distance transform of a one-dimensional list of squared distances, using the lower envelope of parabolas by Felzenszwalb and Huttenlocher.
+	fn squared_distance_transform_1d(values:&[f64]) -> Vec<f64> {
+		let length:usize = values.len();
+		let mut result:Vec<f64> = vec![0.0; length];
+		if length == 0 {
+			return result;
+		}
+
+		// Find the lower envelope of the parabolas rooted at each position.
+		let mut parabola_positions:Vec<usize> = vec![0; length];
+		let mut boundaries:Vec<f64> = vec![0.0; length + 1];
+		let mut parabola_index:usize = 0;
+		boundaries[0] = f64::NEG_INFINITY;
+		boundaries[1] = f64::INFINITY;
+		for position in 1..length {
+			let mut intersection:f64 = Self::parabola_intersection(values, parabola_positions[parabola_index], position);
+			while intersection <= boundaries[parabola_index] {
+				parabola_index -= 1;
+				intersection = Self::parabola_intersection(values, parabola_positions[parabola_index], position);
+			}
+			parabola_index += 1;
+			parabola_positions[parabola_index] = position;
+			boundaries[parabola_index] = intersection;
+			boundaries[parabola_index + 1] = f64::INFINITY;
+		}
+
+		// Sample the lower envelope.
+		parabola_index = 0;
+		for (position, value) in result.iter_mut().enumerate() {
+			while boundaries[parabola_index + 1] < position as f64 {
+				parabola_index += 1;
+			}
+			let parabola_position:usize = parabola_positions[parabola_index];
+			let delta:f64 = position as f64 - parabola_position as f64;
+			*value = delta * delta + values[parabola_position];
+		}
+		result
+	}
+	/// Get the position where the parabolas rooted at two positions of a list of squared distances intersect.
+	fn parabola_intersection(values:&[f64], left:usize, right:usize) -> f64 {
+		((values[right] + (right * right) as f64) - (values[left] + (left * left) as f64)) / (2 * right - 2 * left) as f64
+	}
+}
\ No newline at end of file
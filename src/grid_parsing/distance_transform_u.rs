@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod test {
+	use crate::{ DistanceTarget, Grid, GridRegion, grid_parsing::test_helpers::grid_from_strings };
+
+
+
+	fn brute_force_distance_map(region:&GridRegion, target:DistanceTarget, distance_function:&dyn Fn(f32, f32) -> f32) -> Grid<f32> {
+		let grid:&Grid<bool> = region.grid();
+		let mut features:Vec<[isize; 2]> = Vec::new();
+		for y in -1..grid.height() as isize + 1 {
+			for x in -1..grid.width() as isize + 1 {
+				let inside_grid:bool = x >= 0 && y >= 0 && x < grid.width() as isize && y < grid.height() as isize;
+				let is_feature:bool = match target {
+					DistanceTarget::Outside => !inside_grid || !grid[[x as usize, y as usize]],
+					DistanceTarget::Inside => inside_grid && grid[[x as usize, y as usize]]
+				};
+				if is_feature {
+					features.push([x, y]);
+				}
+			}
+		}
+		let distances:Vec<f32> = (0..grid.height() as isize).flat_map(|y| (0..grid.width() as isize).map(move |x| (x, y))).map(|(x, y)| {
+			features.iter().map(|[feature_x, feature_y]| distance_function((x - feature_x).abs() as f32, (y - feature_y).abs() as f32)).fold(f32::INFINITY, f32::min)
+		}).collect();
+		Grid::new(distances, grid.width(), grid.height())
+	}
+
+
+
+	fn sample_region() -> GridRegion {
+		GridRegion::new(grid_from_strings(&[
+			"          ",
+			"  xxxxxx  ",
+			" xxxxxxxx ",
+			"xxxxxxxxxx",
+			"xxxxxxxxx ",
+			" xxxxxxx  ",
+			"   xxx    ",
+			"    x     "
+		]))
+	}
+
+
+
+	#[test]
+	fn test_euclidean_distance_map() {
+		let region:GridRegion = sample_region();
+		for target in [DistanceTarget::Outside, DistanceTarget::Inside] {
+			let distance_map:Grid<f32> = region.euclidean_distance_map(target);
+			println!("[euclidean distance map {target:?}]\n{}\n", distance_map.map_ref(|distance| format!("{distance:.2}")));
+			let validation:Grid<f32> = brute_force_distance_map(&region, target, &|delta_x, delta_y| (delta_x * delta_x + delta_y * delta_y).sqrt());
+
+			for (distance, expected) in distance_map.iter().zip(validation.iter()) {
+				assert!((distance - expected).abs() < 0.0001, "{distance} should be {expected}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_chebyshev_distance_map() {
+		let region:GridRegion = sample_region();
+		for target in [DistanceTarget::Outside, DistanceTarget::Inside] {
+			let distance_map:Grid<usize> = region.chebyshev_distance_map(target);
+			println!("[chebyshev distance map {target:?}]\n{distance_map}\n");
+			let validation:Grid<f32> = brute_force_distance_map(&region, target, &|delta_x, delta_y| delta_x.max(delta_y));
+
+			assert_eq!(distance_map.data(), &validation.map(|distance| distance as usize).data);
+		}
+	}
+
+	#[test]
+	fn test_manhattan_distance_map() {
+		let region:GridRegion = sample_region();
+		let distance_map:Grid<usize> = region.manhattan_distance_map(DistanceTarget::Outside);
+		println!("[manhattan distance map]\n{distance_map}\n");
+		let validation:Grid<f32> = brute_force_distance_map(&region, DistanceTarget::Outside, &|delta_x, delta_y| delta_x + delta_y);
+
+		assert_eq!(distance_map.data(), &validation.map(|distance| distance as usize).data);
+
+		// Values produced by the previous implementation, which repeatedly removed the edges of the region.
+		let edge_region:GridRegion = GridRegion::new(grid_from_strings(&["      ", " xxxx ", "xxxxxx", "xxxxx ", " xxx  ", "  x   "]));
+		assert_eq!(edge_region.to_edge_distance_map().data_2d(), [
+			[0, 0, 0, 0, 0, 0],
+			[0, 1, 1, 1, 1, 0],
+			[1, 2, 2, 2, 2, 1],
+			[1, 2, 3, 2, 1, 0],
+			[0, 1, 2, 1, 0, 0],
+			[0, 0, 1, 0, 0, 0]
+		]);
+	}
+
+	#[test]
+	fn test_chamfer_distance_map() {
+		let region:GridRegion = GridRegion::new(grid_from_strings(&[
+			"xxxxxxx",
+			"xxxxxxx",
+			"xxxxxxx",
+			"xxxxxxx",
+			"xxxxxxx"
+		]));
+		let distance_map:Grid<usize> = region.chamfer_distance_map(DistanceTarget::Outside);
+		println!("[chamfer distance map]\n{distance_map}\n");
+
+		assert_eq!(distance_map.data_2d()[0], [3, 3, 3, 3, 3, 3, 3]);
+		assert_eq!(distance_map.data_2d()[2], [3, 6, 9, 9, 9, 6, 3]);
+		let inside_map:Grid<usize> = GridRegion::new(grid_from_strings(&["x  ", "   ", "   "])).chamfer_distance_map(DistanceTarget::Inside);
+		assert_eq!(inside_map.data_2d(), [[0, 3, 6], [3, 4, 7], [6, 7, 8]]);
+	}
+
+	#[test]
+	fn test_distance_map_without_target() {
+		let region:GridRegion = GridRegion::new(grid_from_strings(&["   ", "   "]));
+
+		assert!(region.euclidean_distance_map(DistanceTarget::Inside).iter().all(|distance| distance.is_infinite()));
+		assert!(region.chamfer_distance_map(DistanceTarget::Inside).iter().all(|distance| *distance == usize::MAX));
+		assert!(region.euclidean_distance_map(DistanceTarget::Outside).iter().all(|distance| *distance == 0.0));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+use std::cmp::Ordering;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SimilarityMatch {
+	pub position:[usize; 2],
+	pub similarity:f32
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FindOptions {
+	non_overlapping:bool,
+	min_distance:Option<f32>,
+	max_results:Option<usize>,
+	sort_by_score:bool
+}
+impl FindOptions {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create new find options that return all matches in scanning order.
+	pub fn new() -> FindOptions {
+		FindOptions::default()
+	}
+
+	/// Return self only keeping matches that do not overlap a better match.
+	pub fn non_overlapping(mut self) -> Self {
+		self.non_overlapping = true;
+		self
+	}
+
+	/// Return self only keeping matches of which the top-left position is at least the given distance away from that of any better match.
+	pub fn with_min_distance(mut self, min_distance:f32) -> Self {
+		self.min_distance = Some(min_distance);
+		self
+	}
+
+	/// Return self keeping at most the given amount of matches. The best matches are kept.
+	pub fn with_max_results(mut self, max_results:usize) -> Self {
+		self.max_results = Some(max_results);
+		self
+	}
+
+	/// Return self returning the matches from best to worst instead of in scanning order.
+	pub fn sorted_by_score(mut self) -> Self {
+		self.sort_by_score = true;
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Whether or not overlapping matches are suppressed.
+	pub fn is_non_overlapping(&self) -> bool {
+		self.non_overlapping
+	}
+
+	/// Get the minimum distance between matches.
+	pub fn min_distance(&self) -> Option<f32> {
+		self.min_distance
+	}
+
+	/// Get the maximum amount of matches.
+	pub fn max_results(&self) -> Option<usize> {
+		self.max_results
+	}
+
+	/// Whether or not matches are sorted from best to worst.
+	pub fn is_sorted_by_score(&self) -> bool {
+		self.sort_by_score
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Apply the options to matches found in scanning order. Better matches suppress worse matches, equally good matches are preferred in scanning order.
+	pub(crate) fn apply(&self, mut matches:Vec<SimilarityMatch>, sub_grid_size:[usize; 2]) -> Vec<SimilarityMatch> {
+		let suppressing:bool = self.non_overlapping || self.min_distance.is_some();
+		let limiting:bool = self.max_results.is_some_and(|max_results| max_results < matches.len());
+		if !suppressing && !limiting && !self.sort_by_score {
+			return matches;
+		}
+
+		// Order from best to worst. The sort is stable, so equal matches stay in scanning order.
+		matches.sort_by(|left, right| right.similarity.partial_cmp(&left.similarity).unwrap_or(Ordering::Equal));
+
+		// Keep matches that are not suppressed by a better match.
+		let mut kept:Vec<SimilarityMatch> = Vec::new();
+		for candidate in matches {
+			if self.max_results.is_some_and(|max_results| kept.len() >= max_results) {
+				break;
+			}
+			if !suppressing || !kept.iter().any(|better| self.suppresses(better.position, candidate.position, sub_grid_size)) {
+				kept.push(candidate);
+			}
+		}
+
+		// Restore scanning order if required.
+		if !self.sort_by_score {
+			kept.sort_by_key(|found| [found.position[1], found.position[0]]);
+		}
+		kept
+	}
+
+	/// Whether or not a match at the better position suppresses a match at the candidate position.
+	fn suppresses(&self, better:[usize; 2], candidate:[usize; 2], sub_grid_size:[usize; 2]) -> bool {
+		let distance_x:usize = better[0].abs_diff(candidate[0]);
+		let distance_y:usize = better[1].abs_diff(candidate[1]);
+		if self.non_overlapping && distance_x < sub_grid_size[0] && distance_y < sub_grid_size[1] {
+			return true;
+		}
+		if let Some(min_distance) = self.min_distance && ((distance_x * distance_x + distance_y * distance_y) as f32).sqrt() < min_distance {
+			return true;
+		}
+		false
+	}
+}
\ No newline at end of file
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod test {
+	use crate::{ FindOptions, SimilarityMatch };
+
+
+
+	fn test_matches() -> Vec<SimilarityMatch> {
+		vec![
+			SimilarityMatch { position: [0, 0], similarity: 0.75 },
+			SimilarityMatch { position: [1, 0], similarity: 1.0 },
+			SimilarityMatch { position: [5, 0], similarity: 0.75 },
+			SimilarityMatch { position: [0, 3], similarity: 0.9 },
+			SimilarityMatch { position: [6, 3], similarity: 0.8 }
+		]
+	}
+
+	fn positions(matches:&[SimilarityMatch]) -> Vec<[usize; 2]> {
+		matches.iter().map(|found| found.position).collect()
+	}
+
+
+
+	#[test]
+	fn test_default_options() {
+		assert_eq!(FindOptions::new().apply(test_matches(), [2, 2]), test_matches());
+	}
+
+	#[test]
+	fn test_non_overlapping() {
+		let options:FindOptions = FindOptions::new().non_overlapping();
+		assert_eq!(positions(&options.apply(test_matches(), [2, 2])), vec![[1, 0], [5, 0], [0, 3], [6, 3]]);
+		assert_eq!(positions(&options.apply(test_matches(), [2, 4])), vec![[1, 0], [6, 3]]);
+	}
+
+	#[test]
+	fn test_min_distance() {
+		let options:FindOptions = FindOptions::new().with_min_distance(3.5);
+		assert_eq!(positions(&options.apply(test_matches(), [1, 1])), vec![[1, 0], [6, 3]]);
+	}
+
+	#[test]
+	fn test_max_results_and_sorting() {
+		assert_eq!(positions(&FindOptions::new().with_max_results(2).apply(test_matches(), [1, 1])), vec![[1, 0], [0, 3]]);
+		assert_eq!(positions(&FindOptions::new().with_max_results(3).apply(test_matches(), [1, 1])), vec![[1, 0], [0, 3], [6, 3]]);
+		assert_eq!(positions(&FindOptions::new().sorted_by_score().apply(test_matches(), [1, 1])), vec![[1, 0], [0, 3], [6, 3], [0, 0], [5, 0]]);
+		assert_eq!(positions(&FindOptions::new().non_overlapping().sorted_by_score().with_max_results(2).apply(test_matches(), [2, 2])), vec![[1, 0], [0, 3]]);
+	}
+}
\ No newline at end of file
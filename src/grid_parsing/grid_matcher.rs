@@ -1,6 +1,5 @@
-use crate::{ ColorConvertible, Grid, GridByteConvertible, GridMask, ImageConversion };
+use crate::{ CellComparator, ColorConvertible, Grid, GridByteConvertible, GridError, GridMask, ImageConversion };
 use file_ref::FileRef;
-use std::error::Error;
 
 
 
@@ -8,6 +7,7 @@ pub struct GridMatcher<SourceType:PartialEq + Default, TargetType:PartialEq> {
 	filter:Box<dyn Fn(SourceType) -> TargetType + Send  + Sync + 'static>,
 	area_of_interest:Option<[usize; 4]>,
 	mask:Option<GridMask>,
+	comparator:Option<Box<dyn CellComparator<TargetType> + Send + Sync + 'static>>,
 	named_entries:Vec<(String, Grid<TargetType>)>
 }
 impl<SourceType:PartialEq + Default, TargetType:PartialEq> GridMatcher<SourceType, TargetType> {
@@ -20,6 +20,7 @@ impl<SourceType:PartialEq + Default, TargetType:PartialEq> GridMatcher<SourceTyp
 			filter: Box::new(filter),
 			area_of_interest: None,
 			mask: None,
+			comparator: None,
 			named_entries: Vec::new()
 		}
 	}
@@ -36,6 +37,12 @@ impl<SourceType:PartialEq + Default, TargetType:PartialEq> GridMatcher<SourceTyp
 		self
 	}
 
+	/// Return self with a comparator that decides which cells match. Without a comparator, cells only match when they are equal.
+	pub fn with_comparator<Comparator:CellComparator<TargetType> + Send + Sync + 'static>(mut self, comparator:Comparator) -> Self {
+		self.comparator = Some(Box::new(comparator));
+		self
+	}
+
 	/// Return self with an additional named entry.
 	pub fn with_named_entry(mut self, name:&str, grid:Grid<SourceType>) -> Self {
 		self.named_entries.push((name.to_string(), self.process_grid(grid)));
@@ -74,9 +81,11 @@ impl<SourceType:PartialEq + Default, TargetType:PartialEq> GridMatcher<SourceTyp
 		let grid:Grid<TargetType> = self.process_grid(grid);
 		let mut most_similar:Option<(&str, f32)> = None;
 		for (name, entry) in &self.named_entries {
-			let similarity:f32 = match &self.mask {
-				Some(mask) => grid.similarity_to_masked(entry, mask),
-				None => grid.similarity_to(entry)
+			let similarity:f32 = match (&self.mask, &self.comparator) {
+				(Some(mask), Some(comparator)) => grid.similarity_to_masked_by(entry, mask, comparator.as_ref()),
+				(Some(mask), None) => grid.similarity_to_masked(entry, mask),
+				(None, Some(comparator)) => grid.similarity_to_by(entry, comparator.as_ref()),
+				(None, None) => grid.similarity_to(entry)
 			};
 			if most_similar.is_none() || most_similar.unwrap().1 < similarity {
 				most_similar = Some((name, similarity));
@@ -89,9 +98,11 @@ impl<SourceType:PartialEq + Default, TargetType:PartialEq> GridMatcher<SourceTyp
 	pub fn first_similar_to(&self, grid:Grid<SourceType>, similarity_threshold:f32) -> Option<&str> {
 		let grid:Grid<TargetType> = self.process_grid(grid);
 		for (name, entry) in &self.named_entries {
-			let is_similar:bool = match &self.mask {
-				Some(mask) => grid.similar_to_masked(entry, similarity_threshold, mask),
-				None => grid.similar_to(entry, similarity_threshold)
+			let is_similar:bool = match (&self.mask, &self.comparator) {
+				(Some(mask), Some(comparator)) => grid.similar_to_masked_by(entry, similarity_threshold, mask, comparator.as_ref()),
+				(Some(mask), None) => grid.similar_to_masked(entry, similarity_threshold, mask),
+				(None, Some(comparator)) => grid.similar_to_by(entry, similarity_threshold, comparator.as_ref()),
+				(None, None) => grid.similar_to(entry, similarity_threshold)
 			};
 			if is_similar {
 				return Some(name);
@@ -118,7 +129,7 @@ impl<SourceType:PartialEq + Default + ColorConvertible, TargetType:PartialEq + G
 	/* CONSTRUCTOR METHODS */
 
 	/// Create a new cached grid-matcher.
-	pub fn new(source_dir:&str, force_update_all:bool, grid_matcher:GridMatcher<SourceType, TargetType>) -> Result<CachedGridMatcher<SourceType, TargetType, Converter>, Box<dyn Error>> {
+	pub fn new(source_dir:&str, force_update_all:bool, grid_matcher:GridMatcher<SourceType, TargetType>) -> Result<CachedGridMatcher<SourceType, TargetType, Converter>, GridError> {
 		let source_dir:FileRef = FileRef::new(source_dir);
 		let mut cached_matcher:CachedGridMatcher<SourceType, TargetType, Converter> = CachedGridMatcher {
 			source_dir: source_dir.clone(),
@@ -204,12 +215,12 @@ impl<SourceType:PartialEq + Default + ColorConvertible, TargetType:PartialEq + G
 	/* CACHE METHODS */
 
 	/// Get an entry from a cache file.
-	fn entry_from_cache_file(&mut self, cache_file:&FileRef) -> Result<(String, Grid<TargetType>), Box<dyn Error>> {
+	fn entry_from_cache_file(&mut self, cache_file:&FileRef) -> Result<(String, Grid<TargetType>), GridError> {
 		Ok((cache_file.file_name_no_extension().to_string(), Grid::read_from_file(cache_file.path())?))
 	}
 
 	/// Create the cache file for a specific source file.
-	fn create_cache_file_for(&self, source:&FileRef) -> Result<(), Box<dyn Error>> {
+	fn create_cache_file_for(&self, source:&FileRef) -> Result<(), GridError> {
 
 		// Create cache.
 		let image:Grid<SourceType> = Converter::image_from_file(source.path())?;
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-	use crate::{ Grid, GridMask, GridMatcher };
+	use crate::{ Color, ColorTolerance, Grid, GridMask, GridMatcher };
 
 
 
@@ -61,4 +61,17 @@ mod tests {
 		assert_eq!(grid_matcher.first_similar_to(good_target_grid.clone(), 0.5), Some(format!("test_grid_{good_target_grid_index}").as_str()));
 		assert_eq!(grid_matcher.most_similar_to(good_target_grid.clone()), Some((format!("test_grid_{good_target_grid_index}").as_str(), expected_similarity)));
 	}
+
+	#[test]
+	fn test_grid_matcher_comparator() {
+		let entry:Grid<Color> = Grid::new((0..16).map(|index| Color(0xFF000000 + index * 0x00101010)).collect(), 4, 4);
+		let noisy_target:Grid<Color> = entry.map_ref(|color| Color(color.0 ^ 0x00010201));
+		let grid_matcher:GridMatcher<Color, Color> = GridMatcher::new(|color| color).with_named_entry("entry", entry.clone());
+		let tolerant_matcher:GridMatcher<Color, Color> = GridMatcher::new(|color| color).with_comparator(ColorTolerance::new(2)).with_named_entry("entry", entry);
+		println!("[noisy target]\n{noisy_target}\n");
+
+		assert_eq!(grid_matcher.first_similar_to(noisy_target.clone(), 0.9), None);
+		assert_eq!(tolerant_matcher.first_similar_to(noisy_target.clone(), 1.0), Some("entry"));
+		assert_eq!(tolerant_matcher.most_similar_to(noisy_target), Some(("entry", 1.0)));
+	}
 }
\ No newline at end of file
@@ -0,0 +1,134 @@
+use crate::{ Grid, GridNumeric };
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct IntegralGrid {
+	width:usize,
+	height:usize,
+	sums:Vec<f64>, // Sum of all values above and left of each position, with an extra leading row and column of zeroes.
+	squared_sums:Option<Vec<f64>> // Same as sums, but summing the squared values.
+}
+impl IntegralGrid {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new summed-area table of the given grid.
+	pub fn new<T>(source:&Grid<T>) -> IntegralGrid where T:GridNumeric {
+		IntegralGrid {
+			width: source.width,
+			height: source.height,
+			sums: Self::summed_table(source, |value| value),
+			squared_sums: None
+		}
+	}
+
+	/// Create a new summed-area table of the given grid that also holds the sums of the squared values, allowing variance calculations.
+	pub fn with_squared_sums<T>(source:&Grid<T>) -> IntegralGrid where T:GridNumeric {
+		IntegralGrid {
+			width: source.width,
+			height: source.height,
+			sums: Self::summed_table(source, |value| value),
+			squared_sums: Some(Self::summed_table(source, |value| value * value))
+		}
+	}
+
+	/// Create a table where each position holds the sum of the modified values above and left of it.
+	fn summed_table<T, U>(source:&Grid<T>, modification:U) -> Vec<f64> where T:GridNumeric, U:Fn(f64) -> f64 {
+		let table_width:usize = source.width + 1;
+		let mut table:Vec<f64> = vec![0.0; table_width * (source.height + 1)];
+		for y in 0..source.height {
+			let mut row_sum:f64 = 0.0;
+			for x in 0..source.width {
+				row_sum += modification(source.data[y * source.width + x].to_f64());
+				table[(y + 1) * table_width + x + 1] = table[y * table_width + x + 1] + row_sum;
+			}
+		}
+		table
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the width of the source grid.
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Get the height of the source grid.
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	/// Whether or not the table holds the sums of the squared values.
+	pub fn has_squared_sums(&self) -> bool {
+		self.squared_sums.is_some()
+	}
+
+
+
+	/* RECTANGLE METHODS */
+
+	/// Crop the given XYWH bounds to the source grid and return them as a start and end corner.
+	fn cropped_corners(&self, bounds:[usize; 4]) -> [usize; 4] {
+		let left:usize = bounds[0].min(self.width);
+		let top:usize = bounds[1].min(self.height);
+		let right:usize = bounds[0].saturating_add(bounds[2]).min(self.width);
+		let bottom:usize = bounds[1].saturating_add(bounds[3]).min(self.height);
+		[left, top, right, bottom]
+	}
+
+	/// Get the sum of a rectangle from the given table.
+	fn table_sum(&self, table:&[f64], bounds:[usize; 4]) -> f64 {
+		let [left, top, right, bottom] = self.cropped_corners(bounds);
+		let table_width:usize = self.width + 1;
+		table[bottom * table_width + right] - table[top * table_width + right] - table[bottom * table_width + left] + table[top * table_width + left]
+	}
+
+	/// Get the sum of all values in the given XYWH bounds. Bounds are cropped to the source grid.
+	pub fn sum(&self, bounds:[usize; 4]) -> f64 {
+		self.table_sum(&self.sums, bounds)
+	}
+
+	/// Get the amount of cells in the given XYWH bounds. Bounds are cropped to the source grid.
+	pub fn count(&self, bounds:[usize; 4]) -> usize {
+		let [left, top, right, bottom] = self.cropped_corners(bounds);
+		(right - left) * (bottom - top)
+	}
+
+	/// Get the mean of all values in the given XYWH bounds. Bounds are cropped to the source grid. Returns None if the bounds contain no cells.
+	pub fn mean(&self, bounds:[usize; 4]) -> Option<f64> {
+		match self.count(bounds) {
+			0 => None,
+			count => Some(self.sum(bounds) / count as f64)
+		}
+	}
+
+	/// Get the sum of all squared values in the given XYWH bounds. Bounds are cropped to the source grid. Returns None if the table was created without squared sums.
+	pub fn squared_sum(&self, bounds:[usize; 4]) -> Option<f64> {
+		self.squared_sums.as_ref().map(|squared_sums| self.table_sum(squared_sums, bounds))
+	}
+
+	/// Get the population variance of all values in the given XYWH bounds. Bounds are cropped to the source grid. Returns None if the bounds contain no cells or the table was created without squared sums.
+	pub fn variance(&self, bounds:[usize; 4]) -> Option<f64> {
+		let mean:f64 = self.mean(bounds)?;
+		let squared_mean:f64 = self.squared_sum(bounds)? / self.count(bounds) as f64;
+		Some((squared_mean - mean * mean).max(0.0))
+	}
+}
+
+
+
+impl<T> Grid<T> where T:GridNumeric {
+
+	/// Create a summed-area table of the grid.
+	pub fn integral(&self) -> IntegralGrid {
+		IntegralGrid::new(self)
+	}
+
+	/// Create a summed-area table of the grid that also holds the sums of the squared values.
+	pub fn integral_with_squared_sums(&self) -> IntegralGrid {
+		IntegralGrid::with_squared_sums(self)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod test {
+	use crate::{ Grid, IntegralGrid };
+
+
+
+	#[test]
+	fn test_rectangle_sums() {
+		let grid:Grid<u8> = Grid::new((1..=12).collect(), 4, 3);
+		println!("[grid]\n{grid}\n");
+		let integral:IntegralGrid = grid.integral();
+
+		assert_eq!([integral.width(), integral.height()], [4, 3]);
+		assert!(!integral.has_squared_sums());
+		assert_eq!(integral.sum([0, 0, 4, 3]), 78.0);
+		assert_eq!(integral.sum([1, 1, 2, 2]), 6.0 + 7.0 + 10.0 + 11.0);
+		assert_eq!(integral.sum([3, 2, 1, 1]), 12.0);
+		assert_eq!(integral.count([1, 1, 2, 2]), 4);
+		assert_eq!(integral.mean([0, 1, 4, 1]), Some(6.5));
+		assert_eq!(integral.squared_sum([0, 0, 1, 1]), None);
+	}
+
+	#[test]
+	fn test_rectangle_cropping() {
+		let integral:IntegralGrid = Grid::new(vec![1.0f32; 9], 3, 3).integral();
+
+		assert_eq!(integral.sum([2, 2, 10, 10]), 1.0);
+		assert_eq!(integral.count([1, 0, usize::MAX, 2]), 4);
+		assert_eq!(integral.sum([5, 5, 2, 2]), 0.0);
+		assert_eq!(integral.mean([3, 0, 1, 1]), None);
+	}
+
+	#[test]
+	fn test_squared_sums() {
+		let grid:Grid<i32> = Grid::new(vec![
+			2, 4, 4,
+			4, 5, 5,
+			7, 9, 0
+		], 3, 3);
+		let integral:IntegralGrid = IntegralGrid::with_squared_sums(&grid);
+
+		assert!(integral.has_squared_sums());
+		assert_eq!(integral.squared_sum([0, 0, 2, 1]), Some(20.0));
+		assert!((integral.variance([0, 0, 3, 3]).unwrap() - grid.variance().unwrap()).abs() < 1e-9);
+		assert!((integral.variance([0, 0, 2, 3]).unwrap() - grid.sub_grid([0, 0, 2, 3]).map(|value| *value).variance().unwrap()).abs() < 1e-9);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,182 @@
+use crate::{ Grid, GridRegion, Neighborhood };
+
+
+
+impl<T> Grid<T> {
+
+	/// Split the whole grid into connected components of pixels that match the comparing function. In the comparing function, the first value is the value of the neighbor and the second value is the value of the current pixel. Returns the component labels, ordered by the first pixel of each component.
+	pub fn label_components<U>(&self, comparing_function:U) -> ComponentLabels where U:Fn(&T, &T) -> bool {
+		self.label_components_with_neighborhood(&Neighborhood::four_connected(), comparing_function)
+	}
+
+	/// Split the whole grid into connected components of pixels that are attached through the given neighborhood and match the comparing function. Returns the component labels, ordered by the first pixel of each component.
+	pub fn label_components_with_neighborhood<U>(&self, neighborhood:&Neighborhood, comparing_function:U) -> ComponentLabels where U:Fn(&T, &T) -> bool {
+
+		// First pass, join each pixel with all matching neighbors.
+		let mut components:UnionFind = UnionFind::new(self.data.len());
+		for (index, value) in self.data.iter().enumerate() {
+			for neighbor_index in neighborhood.neighbor_indexes(index, self.width, self.height) {
+				if comparing_function(&self.data[neighbor_index], value) {
+					components.union(index, neighbor_index);
+				}
+			}
+		}
+
+		// Second pass, give each component a sequential label and keep track of its properties.
+		let mut root_labels:Vec<u32> = vec![u32::MAX; self.data.len()];
+		let mut component_bounds:Vec<[usize; 4]> = Vec::new(); // Min X, min Y, max X, max Y.
+		let mut areas:Vec<usize> = Vec::new();
+		let mut position_sums:Vec<[usize; 2]> = Vec::new();
+		let mut labels:Vec<u32> = Vec::with_capacity(self.data.len());
+		for index in 0..self.data.len() {
+			let root:usize = components.find(index);
+			let x:usize = index % self.width;
+			let y:usize = index / self.width;
+			if root_labels[root] == u32::MAX {
+				root_labels[root] = component_bounds.len() as u32;
+				component_bounds.push([x, y, x, y]);
+				areas.push(0);
+				position_sums.push([0, 0]);
+			}
+			let label:u32 = root_labels[root];
+			let bounds:&mut [usize; 4] = &mut component_bounds[label as usize];
+			bounds[0] = bounds[0].min(x);
+			bounds[2] = bounds[2].max(x);
+			bounds[3] = y;
+			areas[label as usize] += 1;
+			position_sums[label as usize][0] += x;
+			position_sums[label as usize][1] += y;
+			labels.push(label);
+		}
+
+		ComponentLabels {
+			labels: Grid::new(labels, self.width, self.height),
+			bounds: component_bounds.iter().map(|[min_x, min_y, max_x, max_y]| [*min_x, *min_y, max_x + 1 - min_x, max_y + 1 - min_y]).collect(),
+			areas,
+			position_sums
+		}
+	}
+}
+impl<T> Grid<T> where T:PartialEq {
+
+	/// Split the whole grid into connected components of equal pixels. Returns the component labels, ordered by the first pixel of each component.
+	pub fn label_components_eq(&self) -> ComponentLabels {
+		self.label_components(|left, right| left == right)
+	}
+
+	/// Split the whole grid into connected components of equal pixels that are attached through the given neighborhood. Returns the component labels, ordered by the first pixel of each component.
+	pub fn label_components_eq_with_neighborhood(&self, neighborhood:&Neighborhood) -> ComponentLabels {
+		self.label_components_with_neighborhood(neighborhood, |left, right| left == right)
+	}
+}
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ComponentLabels {
+	labels:Grid<u32>,
+	bounds:Vec<[usize; 4]>, // XYWH bounds of each component.
+	areas:Vec<usize>,
+	position_sums:Vec<[usize; 2]> // Sum of the X and Y coordinates of each component.
+}
+impl ComponentLabels {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the grid of component labels.
+	pub fn labels(&self) -> &Grid<u32> {
+		&self.labels
+	}
+
+	/// Get the amount of components.
+	pub fn component_count(&self) -> usize {
+		self.areas.len()
+	}
+
+	/// Get the XYWH bounds of the component with the given label.
+	pub fn bounds(&self, label:u32) -> Option<[usize; 4]> {
+		self.bounds.get(label as usize).copied()
+	}
+
+	/// Get the amount of pixels in the component with the given label.
+	pub fn area(&self, label:u32) -> Option<usize> {
+		self.areas.get(label as usize).copied()
+	}
+
+	/// Get the average X and Y coordinate of the pixels in the component with the given label.
+	pub fn centroid(&self, label:u32) -> Option<[f32; 2]> {
+		let area:usize = self.area(label)?;
+		let [sum_x, sum_y] = self.position_sums[label as usize];
+		Some([sum_x as f32 / area as f32, sum_y as f32 / area as f32])
+	}
+
+
+
+	/* REGION METHODS */
+
+	/// Create a region of the component with the given label. The region covers the whole grid, so only create regions for the components that are needed.
+	pub fn region(&self, label:u32) -> Option<GridRegion> {
+		let bounds:[usize; 4] = self.bounds(label)?;
+		let mut grid:Grid<bool> = Grid::new(vec![false; self.labels.data.len()], self.labels.width, self.labels.height);
+		for y in bounds[1]..bounds[1] + bounds[3] {
+			for index in y * self.labels.width + bounds[0]..y * self.labels.width + bounds[0] + bounds[2] {
+				grid.data[index] = self.labels.data[index] == label;
+			}
+		}
+		Some(GridRegion { grid, bounds })
+	}
+
+	/// Create a region for each component, where the label is the index of the region in the list. Each region covers the whole grid, use `region` to only create the regions that are needed.
+	pub fn regions(&self) -> Vec<GridRegion> {
+		(0..self.component_count() as u32).filter_map(|label| self.region(label)).collect()
+	}
+}
+
+
+
+struct UnionFind {
+	parents:Vec<usize>,
+	ranks:Vec<u8>
+}
+impl UnionFind {
+
+	/// Create a new union-find where every entry is its own set.
+	fn new(size:usize) -> UnionFind {
+		UnionFind {
+			parents: (0..size).collect(),
+			ranks: vec![0; size]
+		}
+	}
+
+	/// Find the root of the set containing the given entry. Compresses the path on the way.
+	fn find(&mut self, entry:usize) -> usize {
+		let mut root:usize = entry;
+		while self.parents[root] != root {
+			root = self.parents[root];
+		}
+		let mut cursor:usize = entry;
+		while self.parents[cursor] != root {
+			let next:usize = self.parents[cursor];
+			self.parents[cursor] = root;
+			cursor = next;
+		}
+		root
+	}
+
+	/// Join the sets containing the given entries.
+	fn union(&mut self, left:usize, right:usize) {
+		let left_root:usize = self.find(left);
+		let right_root:usize = self.find(right);
+		if left_root == right_root {
+			return;
+		}
+		match self.ranks[left_root].cmp(&self.ranks[right_root]) {
+			std::cmp::Ordering::Less => self.parents[left_root] = right_root,
+			std::cmp::Ordering::Greater => self.parents[right_root] = left_root,
+			std::cmp::Ordering::Equal => {
+				self.parents[right_root] = left_root;
+				self.ranks[left_root] += 1;
+			}
+		}
+	}
+}
\ No newline at end of file
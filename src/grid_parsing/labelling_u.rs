@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod test {
+	use crate::{ ComponentLabels, Grid, GridRegion, Neighborhood };
+
+
+
+	#[test]
+	fn test_label_components() {
+		let grid:Grid<char> = Grid::new(vec![
+			'x', 'x', ' ', 'o',
+			' ', 'x', ' ', 'o',
+			'o', ' ', ' ', 'x',
+			'o', 'o', ' ', 'x'
+		], 4, 4);
+		println!("[grid]\n{grid}\n");
+		let components:ComponentLabels = grid.label_components_eq();
+		println!("[labels]\n{}\n", components.labels());
+
+		assert_eq!(components.labels().data_2d(), [[0, 0, 1, 2], [3, 0, 1, 2], [4, 1, 1, 5], [4, 4, 1, 5]]);
+		assert_eq!(components.component_count(), 6);
+		assert_eq!((0..6).map(|label| components.area(label).unwrap()).collect::<Vec<usize>>(), vec![3, 5, 2, 1, 3, 2]);
+		assert_eq!(components.bounds(0), Some([0, 0, 2, 2]));
+		assert_eq!(components.bounds(1), Some([1, 0, 2, 4]));
+		assert_eq!(components.bounds(3), Some([0, 1, 1, 1]));
+		assert_eq!(components.centroid(1), Some([1.8, 1.6]));
+		assert_eq!(components.centroid(4), Some([1.0 / 3.0, 8.0 / 3.0]));
+		assert_eq!(components.area(6), None);
+		assert!(components.region(6).is_none());
+
+		let regions:Vec<GridRegion> = components.regions();
+		for (index, label) in components.labels().iter().enumerate() {
+			assert!(regions[*label as usize][index]);
+		}
+		for (label, region) in regions.iter().enumerate() {
+			assert_eq!(region.bounds(), components.bounds(label as u32).unwrap());
+			assert_eq!(region.area(), components.area(label as u32).unwrap());
+			assert_eq!(region.centroid(), components.centroid(label as u32));
+		}
+	}
+
+	#[test]
+	fn test_label_components_eight_connected() {
+		let grid:Grid<char> = Grid::new(vec![
+			'x', ' ', 'x',
+			' ', 'x', ' ',
+			'x', ' ', ' '
+		], 3, 3);
+		println!("[grid]\n{grid}\n");
+		let components:ComponentLabels = grid.label_components_eq_with_neighborhood(&Neighborhood::eight_connected());
+		println!("[labels]\n{}\n", components.labels());
+
+		assert_eq!(components.labels().data_2d(), [[0, 1, 0], [1, 0, 1], [0, 1, 1]]);
+		assert_eq!(components.component_count(), 2);
+		assert_eq!(components.area(0), Some(4));
+		assert_eq!(components.bounds(0), Some([0, 0, 3, 3]));
+	}
+
+	#[test]
+	fn test_label_components_similarity() {
+		let grid:Grid<i32> = Grid::new(vec![1, 2, 3, 10, 11, 30], 6, 1);
+		println!("[grid]\n{grid}\n");
+		let components:ComponentLabels = grid.label_components(|left, right| (left - right).abs() <= 1);
+		println!("[labels]\n{}\n", components.labels());
+
+		assert_eq!(components.labels().data(), &[0, 0, 0, 1, 1, 2]);
+		assert_eq!((0..3).map(|label| components.area(label).unwrap()).collect::<Vec<usize>>(), vec![3, 2, 1]);
+		let region:GridRegion = grid.region_at([4, 0], |left, right| (left - right).abs() <= 1);
+		assert_eq!(region.grid(), components.region(1).unwrap().grid());
+	}
+
+	#[test]
+	fn test_label_components_many_small() {
+		let grid:Grid<bool> = Grid::new((0..400 * 400).map(|index| (index % 400 + index / 400) % 2 == 0).collect(), 400, 400);
+		let components:ComponentLabels = grid.label_components_eq();
+
+		assert_eq!(components.component_count(), 400 * 400);
+		assert!((0..400 * 400).all(|label| components.area(label) == Some(1)));
+		assert_eq!(components.bounds(401), Some([1, 1, 1, 1]));
+		assert_eq!(components.centroid(401), Some([1.0, 1.0]));
+		assert_eq!(components.region(401).unwrap().indexes(), vec![401]);
+	}
+}
\ No newline at end of file
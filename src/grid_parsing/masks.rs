@@ -78,6 +78,11 @@ impl GridMask {
 		self.source_grid.height
 	}
 }
+impl AsRef<Grid<bool>> for GridMask {
+	fn as_ref(&self) -> &Grid<bool> {
+		&self.source_grid
+	}
+}
 
 
 
@@ -2,16 +2,51 @@ mod masks;
 mod masks_u;
 mod region;
 mod region_u;
+mod set_operations;
+mod set_operations_u;
+mod labelling;
+mod labelling_u;
+mod distance_transform;
+mod distance_transform_u;
+mod morphology;
+mod morphology_u;
+mod integral_grid;
+mod integral_grid_u;
+mod template_matching;
+mod template_matching_u;
+mod comparators;
+mod comparators_u;
+mod find_options;
+mod find_options_u;
 mod similarity;
 mod similarity_u;
+mod pyramid_search;
+mod pyramid_search_u;
+mod multi_pattern;
+mod multi_pattern_u;
+mod pose_matching;
+mod pose_matching_u;
 mod grid_matcher;
 mod grid_matcher_u;
 mod sub_grid;
 mod sub_grid_u;
 mod pathing;
 mod pathing_u;
+#[cfg(test)]
+mod test_helpers;
 
 pub use masks::GridMask;
 pub use region::GridRegion;
+pub use labelling::ComponentLabels;
+pub use distance_transform::DistanceTarget;
+pub use morphology::StructuringElement;
+pub use integral_grid::IntegralGrid;
+pub use template_matching::{ TemplateMatchMethod, TemplateMatch, TemplateMatchable };
+pub use comparators::{ CellComparator, DistanceComparator, ColorTolerance };
+pub use find_options::{ FindOptions, SimilarityMatch };
+pub use pyramid_search::PyramidSearch;
+pub use multi_pattern::MultiPatternMatcher;
+pub use pose_matching::{ PoseSearch, PoseMatch };
 pub use grid_matcher::GridMatcher;
-pub use grid_matcher::CachedGridMatcher;
\ No newline at end of file
+pub use grid_matcher::CachedGridMatcher;
+pub use pathing::{ PathHeuristic, CostField };
\ No newline at end of file
@@ -0,0 +1,268 @@
+use crate::{ Grid, GridRegion };
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct StructuringElement {
+	grid:Grid<bool>,
+	anchor:[usize; 2],
+	offsets:Vec<[isize; 2]>
+}
+impl StructuringElement {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new structuring element from a grid of positive pixels and the XY position of the anchor within that grid.
+	pub fn new(grid:Grid<bool>, anchor:[usize; 2]) -> StructuringElement {
+		assert!(anchor[0] < grid.width && anchor[1] < grid.height, "Structuring element anchor {:?} does not fall within an element of {}x{}", anchor, grid.width, grid.height);
+		let offsets:Vec<[isize; 2]> = (0..grid.height).flat_map(|y| (0..grid.width).map(move |x| [x, y])).filter(|[x, y]| grid.data[y * grid.width + x]).map(|[x, y]| [x as isize - anchor[0] as isize, y as isize - anchor[1] as isize]).collect();
+		StructuringElement { grid, anchor, offsets }
+	}
+
+	/// Create a new structuring element anchored at the center of the grid.
+	pub fn centered(grid:Grid<bool>) -> StructuringElement {
+		let anchor:[usize; 2] = [grid.width / 2, grid.height / 2];
+		StructuringElement::new(grid, anchor)
+	}
+
+	/// Create a filled rectangle anchored at its center.
+	pub fn rectangle(width:usize, height:usize) -> StructuringElement {
+		StructuringElement::centered(Grid::new(vec![true; width * height], width, height))
+	}
+
+	/// Create a plus-shaped element with arms of the given radius, anchored at its center.
+	pub fn cross(radius:usize) -> StructuringElement {
+		let size:usize = radius * 2 + 1;
+		StructuringElement::centered(Grid::new((0..size * size).map(|index| index % size == radius || index / size == radius).collect(), size, size))
+	}
+
+	/// Create a filled disk of the given radius, anchored at its center.
+	pub fn disk(radius:usize) -> StructuringElement {
+		let size:usize = radius * 2 + 1;
+		let squared_radius:isize = (radius * radius) as isize;
+		StructuringElement::centered(Grid::new((0..size * size).map(|index| {
+			let offset_x:isize = (index % size) as isize - radius as isize;
+			let offset_y:isize = (index / size) as isize - radius as isize;
+			offset_x * offset_x + offset_y * offset_y <= squared_radius
+		}).collect(), size, size))
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the grid of the element.
+	pub fn grid(&self) -> &Grid<bool> {
+		&self.grid
+	}
+
+	/// Get the XY position of the anchor within the grid of the element.
+	pub fn anchor(&self) -> [usize; 2] {
+		self.anchor
+	}
+
+	/// Get the XY offsets of all positive pixels of the element relative to the anchor.
+	pub fn offsets(&self) -> &[[isize; 2]] {
+		&self.offsets
+	}
+}
+
+
+
+impl<T> Grid<T> where T:PartialOrd + Copy {
+
+	/* GRAYSCALE MORPHOLOGY METHODS */
+
+	/// Replace each value with the lowest value covered by the structuring element placed at its position. This is a grayscale erosion.
+	pub fn min_filter(&mut self, element:&StructuringElement) {
+		*self = self.min_filtered(element);
+	}
+
+	/// Replace each value with the highest value covered by the reflected structuring element placed at its position. This is a grayscale dilation.
+	pub fn max_filter(&mut self, element:&StructuringElement) {
+		*self = self.max_filtered(element);
+	}
+
+	/// Get a copy of self where each value is the lowest value covered by the structuring element placed at its position. Positions outside of the grid are ignored.
+	pub fn min_filtered(&self, element:&StructuringElement) -> Grid<T> {
+		self.element_filtered(element.offsets().iter().copied(), |value, current| value < current, None)
+	}
+
+	/// Get a copy of self where each value is the highest value covered by the reflected structuring element placed at its position. Positions outside of the grid are ignored.
+	pub fn max_filtered(&self, element:&StructuringElement) -> Grid<T> {
+		self.element_filtered(element.offsets().iter().map(|[offset_x, offset_y]| [-offset_x, -offset_y]), |value, current| value > current, None)
+	}
+
+	/// Get a copy of self where each value is the most extreme value found at the given offsets from its position. The replace function decides if a value is more extreme than the current one. Offsets outside of the grid find the outside value, or are ignored if there is none. Values without any found values are kept as-is.
+	fn element_filtered<U, V>(&self, offsets:U, replace_function:V, outside_value:Option<T>) -> Grid<T> where U:Iterator<Item = [isize; 2]>, V:Fn(&T, &T) -> bool {
+		let offsets:Vec<[isize; 2]> = offsets.collect();
+		let mut data:Vec<T> = Vec::with_capacity(self.data.len());
+		for y in 0..self.height as isize {
+			for x in 0..self.width as isize {
+				let mut extreme_value:Option<T> = None;
+				for [offset_x, offset_y] in &offsets {
+					let source_x:isize = x + offset_x;
+					let source_y:isize = y + offset_y;
+					let value:T = if source_x < 0 || source_y < 0 || source_x >= self.width as isize || source_y >= self.height as isize {
+						match outside_value {
+							Some(outside_value) => outside_value,
+							None => continue
+						}
+					} else {
+						self.data[source_y as usize * self.width + source_x as usize]
+					};
+					if extreme_value.map(|current| replace_function(&value, &current)).unwrap_or(true) {
+						extreme_value = Some(value);
+					}
+				}
+				data.push(extreme_value.unwrap_or(self.data[y as usize * self.width + x as usize]));
+			}
+		}
+		Grid::new(data, self.width, self.height)
+	}
+}
+
+
+
+impl Grid<bool> {
+
+	/* BINARY MORPHOLOGY METHODS */
+
+	/// Grow the positive pixels by the structuring element.
+	pub fn dilate(&mut self, element:&StructuringElement) {
+		self.max_filter(element);
+	}
+
+	/// Shrink the positive pixels to the positions where the structuring element fits entirely. Positions outside of the grid count as negative, so positive pixels touching the border are eroded from that side.
+	pub fn erode(&mut self, element:&StructuringElement) {
+		*self = self.eroded(element);
+	}
+
+	/// Erode, then dilate, removing positive details smaller than the structuring element.
+	pub fn open(&mut self, element:&StructuringElement) {
+		*self = self.opened(element);
+	}
+
+	/// Dilate, then erode, filling negative details smaller than the structuring element. Positions outside of the grid do not erode the dilated pixels, so no positive pixels are removed.
+	pub fn close(&mut self, element:&StructuringElement) {
+		*self = self.closed(element);
+	}
+
+	/// Get a dilated copy of self.
+	pub fn dilated(&self, element:&StructuringElement) -> Grid<bool> {
+		self.max_filtered(element)
+	}
+
+	/// Get an eroded copy of self. Positions outside of the grid count as negative.
+	pub fn eroded(&self, element:&StructuringElement) -> Grid<bool> {
+		self.eroded_with_outside(element, false)
+	}
+
+	/// Get an opened copy of self.
+	pub fn opened(&self, element:&StructuringElement) -> Grid<bool> {
+		self.eroded(element).dilated(element)
+	}
+
+	/// Get a closed copy of self. Positions outside of the grid do not erode the dilated pixels.
+	pub fn closed(&self, element:&StructuringElement) -> Grid<bool> {
+		self.dilated(element).eroded_with_outside(element, true)
+	}
+
+	/// Get the pixels that are positive in the dilation but not in the erosion of self, which outlines the edges.
+	pub fn morphological_gradient(&self, element:&StructuringElement) -> Grid<bool> {
+		self.dilated(element).combined(&self.eroded(element), |dilated, eroded| dilated && !eroded)
+	}
+
+	/// Get the positive pixels that are removed by opening self, which are the positive details smaller than the structuring element.
+	pub fn top_hat(&self, element:&StructuringElement) -> Grid<bool> {
+		self.combined(&self.opened(element), |original, opened| original && !opened)
+	}
+
+	/// Get the negative pixels that are filled by closing self, which are the negative details smaller than the structuring element.
+	pub fn black_top_hat(&self, element:&StructuringElement) -> Grid<bool> {
+		self.closed(element).combined(self, |closed, original| closed && !original)
+	}
+
+	/// Get the positions where the hit element fits entirely in the positive pixels and the miss element fits entirely in the negative pixels. Positions outside of the grid count as negative.
+	pub fn hit_or_miss(&self, hit_element:&StructuringElement, miss_element:&StructuringElement) -> Grid<bool> {
+		let inverted:Grid<bool> = self.map_ref(|value| !*value);
+		self.eroded(hit_element).combined(&inverted.eroded_with_outside(miss_element, true), |hit, miss| hit && miss)
+	}
+
+	/// Get an eroded copy of self where positions outside of the grid have the given value.
+	fn eroded_with_outside(&self, element:&StructuringElement, outside_value:bool) -> Grid<bool> {
+		self.element_filtered(element.offsets().iter().copied(), |value, current| value < current, Some(outside_value))
+	}
+
+	/// Combine the pixels of self with the pixels of another grid of the same size.
+	fn combined<U>(&self, other:&Grid<bool>, combine_function:U) -> Grid<bool> where U:Fn(bool, bool) -> bool {
+		Grid::new(self.data.iter().zip(&other.data).map(|(left, right)| combine_function(*left, *right)).collect(), self.width, self.height)
+	}
+}
+
+
+
+impl GridRegion {
+
+	/* MORPHOLOGY METHODS */
+
+	/// Grow the region by the structuring element.
+	pub fn dilate(&mut self, element:&StructuringElement) {
+		*self = self.dilated(element);
+	}
+
+	/// Shrink the region to the positions where the structuring element fits entirely. Positions outside of the grid are outside of the region, so the region is eroded from the border of the grid like `remove_edge` does.
+	pub fn erode(&mut self, element:&StructuringElement) {
+		*self = self.eroded(element);
+	}
+
+	/// Erode, then dilate, removing details smaller than the structuring element.
+	pub fn open(&mut self, element:&StructuringElement) {
+		*self = self.opened(element);
+	}
+
+	/// Dilate, then erode, filling holes and gaps smaller than the structuring element.
+	pub fn close(&mut self, element:&StructuringElement) {
+		*self = self.closed(element);
+	}
+
+	/// Get a dilated copy of the region.
+	pub fn dilated(&self, element:&StructuringElement) -> GridRegion {
+		GridRegion::new(self.grid.dilated(element))
+	}
+
+	/// Get an eroded copy of the region. Positions outside of the grid are outside of the region.
+	pub fn eroded(&self, element:&StructuringElement) -> GridRegion {
+		GridRegion::new(self.grid.eroded(element))
+	}
+
+	/// Get an opened copy of the region.
+	pub fn opened(&self, element:&StructuringElement) -> GridRegion {
+		GridRegion::new(self.grid.opened(element))
+	}
+
+	/// Get a closed copy of the region.
+	pub fn closed(&self, element:&StructuringElement) -> GridRegion {
+		GridRegion::new(self.grid.closed(element))
+	}
+
+	/// Get the outline of the region, being the pixels in the dilation but not in the erosion of the region.
+	pub fn morphological_gradient(&self, element:&StructuringElement) -> GridRegion {
+		GridRegion::new(self.grid.morphological_gradient(element))
+	}
+
+	/// Get the details of the region that are smaller than the structuring element.
+	pub fn top_hat(&self, element:&StructuringElement) -> GridRegion {
+		GridRegion::new(self.grid.top_hat(element))
+	}
+
+	/// Get the holes and gaps outside of the region that are smaller than the structuring element.
+	pub fn black_top_hat(&self, element:&StructuringElement) -> GridRegion {
+		GridRegion::new(self.grid.black_top_hat(element))
+	}
+
+	/// Get the positions where the hit element fits entirely inside the region and the miss element fits entirely outside of it.
+	pub fn hit_or_miss(&self, hit_element:&StructuringElement, miss_element:&StructuringElement) -> GridRegion {
+		GridRegion::new(self.grid.hit_or_miss(hit_element, miss_element))
+	}
+}
\ No newline at end of file
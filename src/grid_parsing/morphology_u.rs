@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod test {
+	use crate::{ Grid, GridRegion, StructuringElement, grid_parsing::test_helpers::grid_from_strings };
+
+
+
+	fn grid_to_strings(grid:&Grid<bool>) -> Vec<String> {
+		grid.data_2d().into_iter().map(|row| row.iter().map(|value| if *value { 'x' } else { ' ' }).collect()).collect()
+	}
+
+
+
+	#[test]
+	fn test_structuring_element_shapes() {
+		assert_eq!(grid_to_strings(StructuringElement::cross(1).grid()), [" x ", "xxx", " x "]);
+		assert_eq!(grid_to_strings(StructuringElement::disk(2).grid()), ["  x  ", " xxx ", "xxxxx", " xxx ", "  x  "]);
+		assert_eq!(StructuringElement::rectangle(3, 2).anchor(), [1, 1]);
+		assert_eq!(StructuringElement::new(grid_from_strings(&["xx"]), [0, 0]).offsets(), [[0, 0], [1, 0]]);
+	}
+
+	#[test]
+	fn test_dilate_erode() {
+		let grid:Grid<bool> = grid_from_strings(&[
+			"     ",
+			"     ",
+			"  x  ",
+			"     ",
+			"     "
+		]);
+		let dilated:Grid<bool> = grid.dilated(&StructuringElement::cross(1));
+		println!("[dilated]\n{}\n", grid_to_strings(&dilated).join("\n"));
+
+		assert_eq!(grid_to_strings(&dilated), ["     ", "  x  ", " xxx ", "  x  ", "     "]);
+		assert_eq!(dilated.eroded(&StructuringElement::cross(1)), grid);
+		assert_eq!(grid.eroded(&StructuringElement::cross(1)), grid_from_strings(&["     "; 5]));
+	}
+
+	#[test]
+	fn test_dilate_with_anchor() {
+		let mut grid:Grid<bool> = grid_from_strings(&[
+			"    ",
+			" x  ",
+			"    "
+		]);
+		grid.dilate(&StructuringElement::new(grid_from_strings(&["xxx"]), [0, 0]));
+		println!("[dilated]\n{}\n", grid_to_strings(&grid).join("\n"));
+
+		assert_eq!(grid_to_strings(&grid), ["    ", " xxx", "    "]);
+	}
+
+	#[test]
+	fn test_erode_border() {
+		let grid:Grid<bool> = grid_from_strings(&[
+			"xxx  ",
+			"xxx  ",
+			"     "
+		]);
+		let eroded:Grid<bool> = grid.eroded(&StructuringElement::cross(1));
+		println!("[eroded]\n{}\n", grid_to_strings(&eroded).join("\n"));
+
+		assert_eq!(grid_to_strings(&eroded), ["     ", "     ", "     "]);
+		assert_eq!(grid_to_strings(&grid_from_strings(&["xxxx"; 4]).eroded(&StructuringElement::cross(1))), ["    ", " xx ", " xx ", "    "]);
+		assert_eq!(grid_to_strings(&grid_from_strings(&["xxx"; 3]).eroded(&StructuringElement::rectangle(3, 3))), ["   ", " x ", "   "]);
+		assert_eq!(grid_from_strings(&["xxx"; 3]).closed(&StructuringElement::rectangle(3, 3)), grid_from_strings(&["xxx"; 3]));
+	}
+
+	#[test]
+	fn test_open_close() {
+		let grid:Grid<bool> = grid_from_strings(&[
+			"x       ",
+			" xxxxx  ",
+			" xx xx  ",
+			" xxxxx  ",
+			"      x "
+		]);
+		let element:StructuringElement = StructuringElement::rectangle(3, 3);
+		let opened:Grid<bool> = grid.opened(&element);
+		let closed:Grid<bool> = grid.closed(&element);
+		println!("[opened]\n{}\n", grid_to_strings(&opened).join("\n"));
+		println!("[closed]\n{}\n", grid_to_strings(&closed).join("\n"));
+
+		assert_eq!(grid_to_strings(&opened), ["        ", "        ", "        ", "        ", "        "]);
+		assert!(closed[[3, 2]]);
+		assert!(grid.closed(&element).iter().zip(grid.iter()).all(|(closed, original)| *closed || !*original));
+		assert_eq!(grid_to_strings(&grid.top_hat(&element)), grid_to_strings(&grid));
+		assert_eq!(grid.black_top_hat(&element).iter().filter(|value| **value).count(), closed.iter().filter(|value| **value).count() - grid.iter().filter(|value| **value).count());
+	}
+
+	#[test]
+	fn test_morphological_gradient() {
+		let grid:Grid<bool> = grid_from_strings(&[
+			"       ",
+			" xxxxx ",
+			" xxxxx ",
+			" xxxxx ",
+			"       "
+		]);
+		let gradient:Grid<bool> = grid.morphological_gradient(&StructuringElement::cross(1));
+		println!("[gradient]\n{}\n", grid_to_strings(&gradient).join("\n"));
+
+		assert_eq!(grid_to_strings(&gradient), [" xxxxx ", "xxxxxxx", "xx   xx", "xxxxxxx", " xxxxx "]);
+	}
+
+	#[test]
+	fn test_hit_or_miss() {
+		let grid:Grid<bool> = grid_from_strings(&[
+			"x    ",
+			"   xx",
+			" x   ",
+			"     "
+		]);
+		let hit:StructuringElement = StructuringElement::new(grid_from_strings(&["x"]), [0, 0]);
+		let miss:StructuringElement = StructuringElement::centered(grid_from_strings(&["xxx", "x x", "xxx"]));
+		let isolated:Grid<bool> = grid.hit_or_miss(&hit, &miss);
+		println!("[isolated pixels]\n{}\n", grid_to_strings(&isolated).join("\n"));
+
+		assert_eq!(grid_to_strings(&isolated), ["x    ", "     ", " x   ", "     "]);
+	}
+
+	#[test]
+	fn test_min_max_filter() {
+		let grid:Grid<u8> = Grid::new(vec![
+			1, 2, 3,
+			4, 9, 6,
+			7, 8, 0
+		], 3, 3);
+		let element:StructuringElement = StructuringElement::rectangle(3, 3);
+		let minimum:Grid<u8> = grid.min_filtered(&element);
+		let maximum:Grid<u8> = grid.max_filtered(&element);
+		println!("[min filtered]\n{minimum}\n");
+		println!("[max filtered]\n{maximum}\n");
+
+		assert_eq!(minimum.data, vec![1, 1, 2, 1, 0, 0, 4, 0, 0]);
+		assert_eq!(maximum.data, vec![9, 9, 9, 9, 9, 9, 9, 9, 9]);
+		assert_eq!(grid.max_filtered(&StructuringElement::new(grid_from_strings(&["xx"]), [0, 0])).data, vec![1, 2, 3, 4, 9, 9, 7, 8, 8]);
+	}
+
+	#[test]
+	fn test_region_morphology() {
+		let mut region:GridRegion = GridRegion::new(grid_from_strings(&[
+			"      ",
+			"  x   ",
+			"      ",
+			"      "
+		]));
+		region.dilate(&StructuringElement::rectangle(3, 3));
+		println!("[dilated region]\n{}\n", grid_to_strings(region.grid()).join("\n"));
+
+		assert_eq!(region.bounds(), [1, 0, 3, 3]);
+		region.erode(&StructuringElement::cross(1));
+		assert_eq!(region.bounds(), [2, 1, 1, 1]);
+
+		let mut border_region:GridRegion = GridRegion::new(grid_from_strings(&["xxxx ", "xxxxx", "xxxxx", "xxxxx", " xxxx"]));
+		let eroded:GridRegion = border_region.eroded(&StructuringElement::cross(1));
+		border_region.remove_edge(1);
+		assert_eq!(eroded.grid(), border_region.grid());
+		assert_eq!(eroded.bounds(), border_region.bounds());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,152 @@
+use std::{ collections::{ hash_map::DefaultHasher, HashMap }, hash::{ Hash, Hasher } };
+use crate::Grid;
+
+
+
+const HORIZONTAL_BASE:u64 = 0x100000001B3;
+const VERTICAL_BASE:u64 = 0x9E3779B97F4A7C15;
+
+
+
+pub struct MultiPatternMatcher<T> {
+	templates:Vec<Grid<T>>,
+	size_groups:HashMap<[usize; 2], HashMap<u64, Vec<usize>>> // Template ids by size and hash.
+}
+impl<T> MultiPatternMatcher<T> where T:Hash + PartialEq {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new matcher without any templates.
+	pub fn new() -> MultiPatternMatcher<T> {
+		MultiPatternMatcher {
+			templates: Vec::new(),
+			size_groups: HashMap::new()
+		}
+	}
+
+	/// Return self with an additional template. The id of the template is the amount of templates added before it.
+	pub fn with_template(mut self, template:Grid<T>) -> Self {
+		self.add_template(template);
+		self
+	}
+
+	/// Add a template and return its id. Empty templates are stored, but never found.
+	pub fn add_template(&mut self, template:Grid<T>) -> usize {
+		let id:usize = self.templates.len();
+		if !template.is_empty() {
+			let hash:u64 = Self::window_hashes(&template, template.width, template.height)[0];
+			self.size_groups.entry([template.width, template.height]).or_default().entry(hash).or_default().push(id);
+		}
+		self.templates.push(template);
+		id
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the template with the given id.
+	pub fn template(&self, id:usize) -> Option<&Grid<T>> {
+		self.templates.get(id)
+	}
+
+	/// Get all templates, indexed by their id.
+	pub fn templates(&self) -> &[Grid<T>] {
+		&self.templates
+	}
+
+
+
+	/* USAGE METHODS */
+
+	/// Find every occurrence of every template in the given grid. Returns the id of the template and the topleft coordinates of the occurrence, sorted in scanning order and then by template id.
+	pub fn find_all_in(&self, grid:&Grid<T>) -> Vec<(usize, [usize; 2])> {
+		let cell_hashes:Grid<u64> = grid.map_ref(Self::cell_hash);
+		let mut results:Vec<(usize, [usize; 2])> = Vec::new();
+
+		// Templates of the same size share their window hashes.
+		for (size, hash_groups) in &self.size_groups {
+			if size[0] > grid.width || size[1] > grid.height {
+				continue;
+			}
+			let hashes:Vec<u64> = Self::rolling_hashes(&cell_hashes, size[0], size[1]);
+			let hashes_width:usize = grid.width - size[0] + 1;
+			for (index, hash) in hashes.iter().enumerate() {
+				if let Some(ids) = hash_groups.get(hash) {
+					let position:[usize; 2] = [index % hashes_width, index / hashes_width];
+
+					// Verify the cells to rule out hash collisions.
+					for id in ids {
+						if grid.mismatches_at_position(&self.templates[*id], 0, position, grid.width - size[0], &|left:&T, right:&T| left == right).is_some() {
+							results.push((*id, position));
+						}
+					}
+				}
+			}
+		}
+		results.sort_unstable_by_key(|(id, position)| (position[1], position[0], *id));
+		results
+	}
+
+
+
+	/* HASHING METHODS */
+
+	/// Hash a single cell.
+	fn cell_hash(value:&T) -> u64 {
+		let mut hasher:DefaultHasher = DefaultHasher::new();
+		value.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	/// Get the hashes of all windows of the given size in the grid.
+	fn window_hashes(grid:&Grid<T>, width:usize, height:usize) -> Vec<u64> {
+		Self::rolling_hashes(&grid.map_ref(Self::cell_hash), width, height)
+	}
+
+	/// Get the hash of each window of the given size in a grid of cell hashes. Rows are hashed with a horizontal rolling hash, after which columns of row hashes are combined with a vertical rolling hash.
+	fn rolling_hashes(cell_hashes:&Grid<u64>, width:usize, height:usize) -> Vec<u64> {
+		let hashes_width:usize = cell_hashes.width - width + 1;
+		let hashes_height:usize = cell_hashes.height - height + 1;
+
+		// Horizontal pass.
+		let horizontal_shift:u64 = HORIZONTAL_BASE.wrapping_pow(width as u32 - 1);
+		let mut row_hashes:Vec<u64> = Vec::with_capacity(hashes_width * cell_hashes.height);
+		for row in cell_hashes.data.chunks(cell_hashes.width) {
+			let mut hash:u64 = row[..width].iter().fold(0, |hash, cell| hash.wrapping_mul(HORIZONTAL_BASE).wrapping_add(*cell));
+			row_hashes.push(hash);
+			for x in 1..hashes_width {
+				hash = hash.wrapping_sub(row[x - 1].wrapping_mul(horizontal_shift)).wrapping_mul(HORIZONTAL_BASE).wrapping_add(row[x + width - 1]);
+				row_hashes.push(hash);
+			}
+		}
+
+		// Vertical pass.
+		let vertical_shift:u64 = VERTICAL_BASE.wrapping_pow(height as u32 - 1);
+		let mut hashes:Vec<u64> = vec![0; hashes_width * hashes_height];
+		for x in 0..hashes_width {
+			let mut hash:u64 = (0..height).fold(0, |hash, y| hash.wrapping_mul(VERTICAL_BASE).wrapping_add(row_hashes[y * hashes_width + x]));
+			hashes[x] = hash;
+			for y in 1..hashes_height {
+				hash = hash.wrapping_sub(row_hashes[(y - 1) * hashes_width + x].wrapping_mul(vertical_shift)).wrapping_mul(VERTICAL_BASE).wrapping_add(row_hashes[(y + height - 1) * hashes_width + x]);
+				hashes[y * hashes_width + x] = hash;
+			}
+		}
+		hashes
+	}
+}
+impl<T> Default for MultiPatternMatcher<T> where T:Hash + PartialEq {
+	fn default() -> Self {
+		MultiPatternMatcher::new()
+	}
+}
+
+
+
+impl<T> Grid<T> where T:Hash + PartialEq {
+
+	/// Find every occurrence of every template of the matcher in self. Returns the id of the template and the topleft coordinates of the occurrence, sorted in scanning order and then by template id.
+	pub fn find_all_patterns(&self, matcher:&MultiPatternMatcher<T>) -> Vec<(usize, [usize; 2])> {
+		matcher.find_all_in(self)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod test {
+	use crate::{ Grid, MultiPatternMatcher };
+
+
+
+	#[test]
+	fn test_multi_pattern_find_all() {
+		let grid:Grid<char> = Grid::new("abcabcxabcabc  bab c xab".chars().collect(), 8, 3);
+		println!("[grid]\n{grid}\n");
+		let matcher:MultiPatternMatcher<char> = MultiPatternMatcher::new()
+			.with_template(Grid::new(vec!['a', 'b'], 2, 1))
+			.with_template(Grid::new(vec!['b', 'c'], 2, 1))
+			.with_template(Grid::new(vec!['b', 'c', 'c', 'a'], 2, 2))
+			.with_template(Grid::new(vec!['x', 'y'], 2, 1))
+			.with_template(Grid::new(vec!['a'; 12], 4, 3));
+
+		assert_eq!(grid.find_all_patterns(&matcher), vec![
+			(0, [0, 0]), (1, [1, 0]), (2, [1, 0]), (0, [3, 0]), (1, [4, 0]),
+			(1, [0, 1]), (0, [2, 1]), (1, [3, 1]),
+			(0, [0, 2]), (0, [6, 2])
+		]);
+		assert_eq!(matcher.template(3), Some(&Grid::new(vec!['x', 'y'], 2, 1)));
+		assert_eq!(matcher.templates().len(), 5);
+	}
+
+	#[test]
+	fn test_multi_pattern_matches_find_all() {
+		let grid:Grid<u8> = Grid::new((0..40 * 30).map(|index:u64| ((index * index * 7919 + index * 104_729) % 1_000_003 % 3) as u8).collect(), 40, 30);
+		let templates:Vec<Grid<u8>> = vec![
+			grid.clone().take([3, 4, 2, 2]),
+			grid.clone().take([10, 10, 3, 2]),
+			grid.clone().take([20, 5, 2, 2]),
+			grid.clone().take([0, 0, 1, 3]),
+			Grid::new(vec![0; 4], 2, 2)
+		];
+		let mut matcher:MultiPatternMatcher<u8> = MultiPatternMatcher::default();
+		for template in &templates {
+			matcher.add_template(template.clone());
+		}
+
+		let mut expected:Vec<(usize, [usize; 2])> = templates.iter().enumerate().flat_map(|(id, template)| grid.find_all(template, 1.0).into_iter().map(move |position| (id, position))).collect();
+		expected.sort_unstable_by_key(|(id, position)| (position[1], position[0], *id));
+		assert!(expected.len() > templates.len());
+		assert_eq!(matcher.find_all_in(&grid), expected);
+	}
+
+	#[test]
+	fn test_multi_pattern_edge_cases() {
+		let grid:Grid<u8> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		let mut matcher:MultiPatternMatcher<u8> = MultiPatternMatcher::new();
+		assert_eq!(matcher.find_all_in(&grid), Vec::new());
+		assert_eq!(matcher.add_template(Grid::new(Vec::new(), 0, 0)), 0);
+		assert_eq!(matcher.add_template(Grid::new(vec![1; 9], 3, 3)), 1);
+		assert_eq!(matcher.add_template(grid.clone()), 2);
+		assert_eq!(matcher.find_all_in(&grid), vec![(2, [0, 0])]);
+		assert_eq!(matcher.find_all_in(&Grid::new(Vec::new(), 0, 0)), Vec::new());
+	}
+}
\ No newline at end of file
@@ -1,7 +1,7 @@
-use std::{ error::Error, ops::Add };
+use std::ops::Add;
 use urge_prique::WeighedPriorityQueue;
 
-use crate::{ Grid, GridIndexer };
+use crate::{ Grid, GridError, GridIndexer, GridNumeric, GridView, Neighborhood };
 use super::GridRegion;
 
 
@@ -9,30 +9,32 @@ use super::GridRegion;
 impl GridRegion {
 
 	// Find a path from one index to another. Will only move over positive pixels in the region.
-	pub fn find_path<U, V>(&self, start:U, end:V) -> Result<Vec<[usize; 2]>, Box<dyn Error>> where U:GridIndexer, V:GridIndexer {
+	pub fn find_path<U, V>(&self, start:U, end:V) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer, V:GridIndexer {
+		self.find_path_with_neighborhood(start, end, &Neighborhood::four_connected())
+	}
+
+	// Find a path from one index to another, moving between the positions of the given neighborhood. Will only move over positive pixels in the region.
+	pub fn find_path_with_neighborhood<U, V>(&self, start:U, end:V, neighborhood:&Neighborhood) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer, V:GridIndexer {
 
 		// Find and validate start and end.
-		let start_index:usize = start.to_grid_index(self.grid());
-		let end_index:usize = end.to_grid_index(self.grid());
-		if !self[start_index] {
-			return Err("Start coordinate falls outside of the region.".into());
-		}
-		if !self[end_index] {
-			return Err("Start coordinate falls outside of the region.".into());
+		let start_index:usize = start.to_checked_grid_index(&self.grid)?;
+		let end_index:usize = end.to_checked_grid_index(&self.grid)?;
+		for index in [start_index, end_index] {
+			if !self[index] {
+				return Err(GridError::OutsideOfRegion { position: self.grid.index_to_xy(index) });
+			}
 		}
 
 		// Modify arguments to sub-grid.
 		let start_coord:[usize; 2] = self.grid.index_to_xy(start_index);
 		let end_coord:[usize; 2] = self.grid.index_to_xy(end_index);
-		let bounds_grid:Grid<&bool> = self.bounds_sub_grid();
+		let bounds_grid:GridView<bool> = self.bounds_view();
 		let start_index:usize = bounds_grid.xy_to_index(start_coord[0] - self.bounds[0], start_coord[1] - self.bounds[1]);
 		let end_index:usize = bounds_grid.xy_to_index(end_coord[0] - self.bounds[0], end_coord[1] - self.bounds[1]);
-		let max_x:usize = bounds_grid.width - 1;
-		let max_y:usize = bounds_grid.len() - bounds_grid.width;
 
 		// Keep checking positions in the queue.
-		let mut search_grid:Grid<Option<usize>> = Grid::new(vec![None; bounds_grid.width * bounds_grid.height], bounds_grid.width, bounds_grid.height); // For each node, keeps the amount of steps to the start coordinate.
-		let mut queue:Vec<(usize, usize)> = Vec::with_capacity(bounds_grid.width * bounds_grid.height); // Has a lot of space, likely too much. Stops it from moving around in memory when growing.
+		let mut search_grid:Grid<Option<usize>> = Grid::new(vec![None; bounds_grid.len()], bounds_grid.width(), bounds_grid.height()); // For each node, keeps the amount of steps to the start coordinate.
+		let mut queue:Vec<(usize, usize)> = Vec::with_capacity(bounds_grid.len()); // Has a lot of space, likely too much. Stops it from moving around in memory when growing.
 		queue.push((start_index, start_index));
 		let mut queue_cursor:usize = 0; // Keep a cursor to prevent moving the entire queue through memory on resizing.
 		while queue_cursor < queue.len() {
@@ -61,8 +63,8 @@ impl GridRegion {
 			search_grid[current_index] = Some(previous_index);
 
 			// Add neighbors to queue.
-			for neighbor_index in bounds_grid._index_neighbors(current_index, max_x, max_y) {
-				if *bounds_grid[neighbor_index] && search_grid[neighbor_index].is_none() && !queue.iter().any(|(index, _)| index == &neighbor_index) {
+			for neighbor_index in neighborhood.neighbor_indexes(current_index, bounds_grid.width(), bounds_grid.height()) {
+				if bounds_grid[neighbor_index] && search_grid[neighbor_index].is_none() && !queue.iter().any(|(index, _)| index == &neighbor_index) {
 					queue.push((neighbor_index, current_index));
 				}
 			}
@@ -71,38 +73,80 @@ impl GridRegion {
 		}
 
 		// No path was found.
-		Err("Could not find path.".into())
+		Err(GridError::UnreachablePath)
 	}
 }
 impl<T> Grid<T> where T:PartialEq {
 
 	// Find a path from one index to another. Will only move over pixels that are equal to the starting pixel.
-	pub fn find_path<U, V>(&self, start:U, end:V) -> Result<Vec<[usize; 2]>, Box<dyn Error>> where U:GridIndexer, V:GridIndexer {
-		self.region_at_eq(start.to_grid_index(self)).find_path(start, end)
+	pub fn find_path<U, V>(&self, start:U, end:V) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer, V:GridIndexer {
+		self.find_path_with_neighborhood(start, end, &Neighborhood::four_connected())
+	}
+
+	// Find a path from one index to another, moving between the positions of the given neighborhood. Will only move over pixels that are equal to the starting pixel.
+	pub fn find_path_with_neighborhood<U, V>(&self, start:U, end:V, neighborhood:&Neighborhood) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer, V:GridIndexer {
+		let start_index:usize = start.to_checked_grid_index(self)?;
+		self.region_at_eq_with_neighborhood(start_index, neighborhood).find_path_with_neighborhood(start, end, neighborhood)
 	}
 }
 impl<T> Grid<T> {
 
-	// Find a path from one index to another. Will only move over pixels that are equal to the starting pixel.
-	pub fn find_path_weighed<U, V, W, X>(&self, start:U, end:V, weight_function:W) -> Result<Vec<[usize; 2]>, Box<dyn Error>> where U:GridIndexer, V:GridIndexer, W:Fn((usize, &T), (usize, &T)) -> Option<X>, X:Ord + Add<Output=X> + Clone + Copy + Default {	
-		
+	// Find the cheapest path from one index to another. The weight function returns the cost of moving from one pixel to its neighbor, or None if the move is not allowed.
+	pub fn find_path_weighed<U, V, W, X>(&self, start:U, end:V, weight_function:W) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer, V:GridIndexer, W:Fn((usize, &T), (usize, &T)) -> Option<X>, X:Ord + Add<Output=X> + Clone + Copy + Default {
+		self.find_path_weighed_with_neighborhood(start, end, &Neighborhood::four_connected(), weight_function)
+	}
+
+	// Find the cheapest path from one index to another, moving between the positions of the given neighborhood. The weight function returns the cost of moving from one pixel to its neighbor, or None if the move is not allowed.
+	pub fn find_path_weighed_with_neighborhood<U, V, W, X>(&self, start:U, end:V, neighborhood:&Neighborhood, weight_function:W) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer, V:GridIndexer, W:Fn((usize, &T), (usize, &T)) -> Option<X>, X:Ord + Add<Output=X> + Clone + Copy + Default {
+		self.find_path_astar_with_neighborhood(start, end, neighborhood, weight_function, |_, _| X::default())
+	}
+
+	// Find the cheapest path from one index to another using A*. The heuristic estimates the remaining weight from a position to the end position. It should never overestimate, otherwise the path found might not be the cheapest.
+	pub fn find_path_astar<U, V, W, X, Y>(&self, start:U, end:V, weight_function:W, heuristic:Y) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer, V:GridIndexer, W:Fn((usize, &T), (usize, &T)) -> Option<X>, X:Ord + Add<Output=X> + Clone + Copy + Default, Y:Fn([usize; 2], [usize; 2]) -> X {
+		self.find_path_astar_with_neighborhood(start, end, &Neighborhood::four_connected(), weight_function, heuristic)
+	}
+
+	// Find the cheapest path from one index to another using A*, moving between the positions of the given neighborhood. The heuristic estimates the remaining weight from a position to the end position. It should never overestimate, otherwise the path found might not be the cheapest.
+	pub fn find_path_astar_with_neighborhood<U, V, W, X, Y>(&self, start:U, end:V, neighborhood:&Neighborhood, weight_function:W, heuristic:Y) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer, V:GridIndexer, W:Fn((usize, &T), (usize, &T)) -> Option<X>, X:Ord + Add<Output=X> + Clone + Copy + Default, Y:Fn([usize; 2], [usize; 2]) -> X {
+
 		// Find and validate start and end.
-		let start_index:usize = start.to_grid_index(self);
-		let end_index:usize = end.to_grid_index(self);
-		if start_index >= self.len() {
-			return Err("Start coordinate falls outside of the grid.".into());
+		let start_index:usize = start.to_checked_grid_index(self)?;
+		let end_index:usize = end.to_checked_grid_index(self)?;
+
+		// Search and backtrack path.
+		let end_coord:[usize; 2] = self.index_to_xy(end_index);
+		let origin_grid:Grid<Option<(usize, X)>> = self.weighed_search(&[start_index], Some(end_index), neighborhood, weight_function, |index| heuristic(self.index_to_xy(index), end_coord));
+		if origin_grid[end_index].is_none() {
+			return Err(GridError::UnreachablePath);
 		}
-		if end_index >= self.len() {
-			return Err("Start coordinate falls outside of the grid.".into());
+		origin_grid.backtrack_origins(end_index, |origin| origin.map(|(previous_index, _)| previous_index))
+	}
+
+	/// Create a field of the lowest total weight to reach each position from the nearest of the given starts. Unreachable positions are None. Starts outside of the grid are ignored. Use `CostField::path_to` to get a path to any position in the field.
+	pub fn cost_field<U, W, X>(&self, starts:&[U], weight_function:W) -> CostField<X> where U:GridIndexer, W:Fn((usize, &T), (usize, &T)) -> Option<X>, X:Ord + Add<Output=X> + Clone + Copy + Default {
+		self.cost_field_with_neighborhood(starts, &Neighborhood::four_connected(), weight_function)
+	}
+
+	/// Create a field of the lowest total weight to reach each position from the nearest of the given starts, moving between the positions of the given neighborhood. Unreachable positions are None. Starts outside of the grid are ignored.
+	pub fn cost_field_with_neighborhood<U, W, X>(&self, starts:&[U], neighborhood:&Neighborhood, weight_function:W) -> CostField<X> where U:GridIndexer, W:Fn((usize, &T), (usize, &T)) -> Option<X>, X:Ord + Add<Output=X> + Clone + Copy + Default {
+		let start_indexes:Vec<usize> = starts.iter().filter_map(|start| start.try_to_grid_index(self)).collect();
+		let origin_grid:Grid<Option<(usize, X)>> = self.weighed_search(&start_indexes, None, neighborhood, weight_function, |_| X::default());
+		CostField {
+			origins: origin_grid.map_ref(|origin| origin.map(|(previous_index, _)| previous_index)),
+			costs: origin_grid.map_ref(|origin| origin.map(|(_, cost)| cost))
 		}
-		let max_x:usize = self.width - 1;
-		let max_y:usize = self.len() - self.width;
+	}
+
+	/// Run a best-first search from the start indexes. Expands the position with the lowest total weight plus heuristic first. Returns a grid that keeps the origin and total weight of each claimed position. Start positions are their own origin. Stops as soon as the end index is claimed.
+	fn weighed_search<W, X, Y>(&self, start_indexes:&[usize], end_index:Option<usize>, neighborhood:&Neighborhood, weight_function:W, heuristic:Y) -> Grid<Option<(usize, X)>> where W:Fn((usize, &T), (usize, &T)) -> Option<X>, X:Ord + Add<Output=X> + Clone + Copy + Default, Y:Fn(usize) -> X {
 
 		// Keep checking positions in the queue.
 		let mut origin_grid:Grid<Option<(usize, X)>> = Grid::new(vec![None; self.width * self.height], self.width, self.height); // For each node, keep the origin and value of total weight to get here.
-		let mut queue:WeighedPriorityQueue<X, (usize, usize, X, &T)> = WeighedPriorityQueue::new(|data:&(usize, usize, X, &T)| data.2);
-		queue.push((start_index, start_index, X::default(), &self[start_index]));
-		while let Some((current_index, origin_index, current_weight, current_value)) = queue.pop() {
+		let mut queue:WeighedPriorityQueue<X, (usize, usize, X, X, &T)> = WeighedPriorityQueue::new(|data:&(usize, usize, X, X, &T)| data.3);
+		for start_index in start_indexes {
+			queue.push((*start_index, *start_index, X::default(), heuristic(*start_index), &self[*start_index]));
+		}
+		while let Some((current_index, origin_index, current_weight, _priority, current_value)) = queue.pop() {
 
 			// Skip this field if it was already taken, otherwise claim it.
 			if origin_grid[current_index].is_some() {
@@ -111,37 +155,118 @@ impl<T> Grid<T> {
 				origin_grid[current_index] = Some((origin_index, current_weight));
 			}
 			
-			// If end found, backtrack and return path.
-			if current_index == end_index {
-				let mut path_indexes:Vec<usize> = vec![current_index];
-				let mut backtrack_cursor:usize = current_index;
-				while let Some((previous_index, _)) = &origin_grid[backtrack_cursor] {
-					if path_indexes.contains(previous_index) {
-						return Err("Repeating loop in backtracking path.".into());
-					}
-					path_indexes.push(*previous_index);
-					if previous_index == &start_index {
-						break;
-					}
-					backtrack_cursor = *previous_index;
-				}
-				return Ok(path_indexes.into_iter().rev().map(|index| self.index_to_xy(index)).collect());
+			// Stop when the end was found.
+			if Some(current_index) == end_index {
+				break;
 			}
 
 			// Add neighbors to queue.
-			for neighbor_index in self._index_neighbors(current_index, max_x, max_y) {
-				if self.index_is_valid(neighbor_index) && origin_grid[neighbor_index].is_none() {
+			for neighbor_index in neighborhood.neighbor_indexes(current_index, self.width, self.height) {
+				if origin_grid[neighbor_index].is_none() {
 					let neighbor_value:&T = &self[neighbor_index];
 					if let Some(weight_addition_to_neighbor) = weight_function((current_index, current_value), (neighbor_index, neighbor_value)) {
-						println!("\t+ {:?}", self.index_to_xy(neighbor_index));
-						let neighbor_weight:X = current_weight.clone() + weight_addition_to_neighbor;
-						queue.push((neighbor_index, current_index, neighbor_weight, neighbor_value));
+						let neighbor_weight:X = current_weight + weight_addition_to_neighbor;
+						queue.push((neighbor_index, current_index, neighbor_weight, neighbor_weight + heuristic(neighbor_index), neighbor_value));
 					}
 				}
 			}
 		}
+		origin_grid
+	}
+}
+impl<T> Grid<T> {
 
-		// No path was found.
-		Err("Could not find path.".into())
+	/// Follow the origins in an origin grid from the end index back to a start position, which is its own origin. The origin function gets the origin index from a value of the grid. Returns the path from start to end.
+	fn backtrack_origins<U>(&self, end_index:usize, origin_function:U) -> Result<Vec<[usize; 2]>, GridError> where U:Fn(&T) -> Option<usize> {
+		let mut path_indexes:Vec<usize> = vec![end_index];
+		let mut backtrack_cursor:usize = end_index;
+		while let Some(previous_index) = origin_function(&self[backtrack_cursor]) {
+			if previous_index == backtrack_cursor {
+				return Ok(path_indexes.into_iter().rev().map(|index| self.index_to_xy(index)).collect());
+			}
+			if path_indexes.len() > self.len() {
+				return Err(GridError::UnreachablePath);
+			}
+			path_indexes.push(previous_index);
+			backtrack_cursor = previous_index;
+		}
+		Err(GridError::UnreachablePath)
+	}
+}
+
+
+
+pub struct PathHeuristic;
+impl PathHeuristic {
+
+	/// The amount of orthogonal steps between two positions. Suited for 4-connected movement.
+	pub fn manhattan<X>(from:[usize; 2], to:[usize; 2]) -> X where X:GridNumeric {
+		let [delta_x, delta_y] = Self::deltas(from, to);
+		X::from_f64(delta_x + delta_y)
+	}
+
+	/// The straight-line distance between two positions, rounded down. Suited for any-angle movement.
+	pub fn euclidean<X>(from:[usize; 2], to:[usize; 2]) -> X where X:GridNumeric {
+		let [delta_x, delta_y] = Self::deltas(from, to);
+		X::from_f64((delta_x * delta_x + delta_y * delta_y).sqrt().floor())
+	}
+
+	/// The amount of steps between two positions when diagonal steps cost the same as orthogonal steps. Suited for 8-connected movement with uniform weights.
+	pub fn chebyshev<X>(from:[usize; 2], to:[usize; 2]) -> X where X:GridNumeric {
+		let [delta_x, delta_y] = Self::deltas(from, to);
+		X::from_f64(delta_x.max(delta_y))
+	}
+
+	/// The distance between two positions when diagonal steps cost the square root of 2, rounded down. Suited for 8-connected movement with diagonal weights.
+	pub fn octile<X>(from:[usize; 2], to:[usize; 2]) -> X where X:GridNumeric {
+		let [delta_x, delta_y] = Self::deltas(from, to);
+		X::from_f64((delta_x.max(delta_y) + (std::f64::consts::SQRT_2 - 1.0) * delta_x.min(delta_y)).floor())
+	}
+
+	/// Get the absolute X and Y difference between two positions.
+	fn deltas(from:[usize; 2], to:[usize; 2]) -> [f64; 2] {
+		[from[0].abs_diff(to[0]) as f64, from[1].abs_diff(to[1]) as f64]
+	}
+}
+
+
+
+#[derive(Clone, PartialEq)]
+pub struct CostField<X> {
+	origins:Grid<Option<usize>>, // For each position, the position it was reached from. Starts are their own origin.
+	costs:Grid<Option<X>> // For each position, the total weight to reach it.
+}
+impl<X> CostField<X> where X:Copy {
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the width of the field.
+	pub fn width(&self) -> usize {
+		self.origins.width
+	}
+
+	/// Get the height of the field.
+	pub fn height(&self) -> usize {
+		self.origins.height
+	}
+
+	/// Get the lowest total weight to reach the given position from the nearest start. Returns None if the position is unreachable or outside of the field.
+	pub fn cost<U>(&self, position:U) -> Option<X> where U:GridIndexer {
+		position.try_to_grid_index(&self.costs).and_then(|index| self.costs[index])
+	}
+
+	/// Get a grid of the lowest total weight to reach each position from the nearest start. Unreachable positions are None. Returns the grid kept by the field instead of building a new one on every call, clone it to get an owned grid.
+	pub fn costs(&self) -> &Grid<Option<X>> {
+		&self.costs
+	}
+
+
+
+	/* PATH METHODS */
+
+	/// Follow the moves the search made back from the target to the nearest start. Returns the path from that start to the target.
+	pub fn path_to<U>(&self, target:U) -> Result<Vec<[usize; 2]>, GridError> where U:GridIndexer {
+		let target_index:usize = target.to_checked_grid_index(&self.origins)?;
+		self.origins.backtrack_origins(target_index, |origin| *origin)
 	}
 }
\ No newline at end of file
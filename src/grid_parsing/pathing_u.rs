@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-	use crate::Grid;
+	use crate::{ CostField, Grid, GridError, Neighborhood, PathHeuristic };
 
 
 
@@ -26,6 +26,15 @@ mod test {
 		assert_eq!(path, vec![[1, 0], [1, 1], [0, 1], [0, 2], [0, 3], [0, 4], [1, 4], [2, 4], [2, 3], [3, 3], [4, 3], [4, 4], [5, 4], [6, 4], [7, 4], [7, 3], [8, 3], [9, 3], [9, 2], [9, 1], [9, 0]]);
 	}
 
+	#[test]
+	fn test_path_finding_out_of_bounds() {
+		let grid:Grid<char> = Grid::new(vec!['x'; 6], 3, 2);
+
+		assert!(matches!(grid.find_path([3, 0], [0, 0]), Err(GridError::OutOfBounds { position: [3, 0], size: [3, 2] })));
+		assert!(matches!(grid.find_path([0, 0], [0, 2]), Err(GridError::OutOfBounds { .. })));
+		assert!(matches!(grid.find_path(6, 0), Err(GridError::OutOfBounds { .. })));
+	}
+
 
 	#[test]
 	fn test_weighed_path_finding() {
@@ -48,4 +57,103 @@ mod test {
 		
 		assert_eq!(path, vec![[1, 0], [1, 1], [0, 1], [0, 2], [0, 3], [0, 4], [1, 4], [2, 4], [3, 4], [4, 4], [5, 4], [6, 4], [7, 4], [7, 3], [8, 3], [9, 3], [9, 2], [9, 1], [9, 0]]);
 	}
+	#[test]
+	fn test_astar_path_finding() {
+		let maze:[&str; 5] = [
+			" x   xxx x",
+			"xxxx     x",
+			"x o  xx  x",
+			"x xxx  xxx",
+			"xxx+xxxx  "
+		];
+
+		let grid:Grid<char> = Grid::new(maze.iter().flat_map(|line| line.chars()).collect(), 10, 5);
+		println!("[grid]\n{grid}\n");
+		let weight_function = |(_left_index, _left):(usize, &char), (_right_index, right):(usize, &char)| match right { 'o' => Some(10), '+' => Some(2), 'x' => Some(1), _ => None };
+		let dijkstra_path:Vec<[usize; 2]> = grid.find_path_weighed([1, 0], [9, 0], weight_function).unwrap();
+		let astar_path:Vec<[usize; 2]> = grid.find_path_astar([1, 0], [9, 0], weight_function, PathHeuristic::manhattan).unwrap();
+		println!("[astar path]\n{astar_path:?}\n");
+
+		assert_eq!(astar_path, dijkstra_path);
+		assert!(grid.find_path_astar([1, 0], [9, 4], weight_function, PathHeuristic::manhattan).is_err());
+	}
+
+	#[test]
+	fn test_astar_path_finding_diagonal() {
+		let grid:Grid<u8> = Grid::new(vec![1; 20 * 20], 20, 20);
+		let weight_function = |(from_index, _from):(usize, &u8), (to_index, _to):(usize, &u8)| Some(if from_index % 20 != to_index % 20 && from_index / 20 != to_index / 20 { 14 } else { 10 });
+		let heuristic = |from:[usize; 2], to:[usize; 2]| PathHeuristic::octile::<usize>(from, to) * 10;
+		let path:Vec<[usize; 2]> = grid.find_path_astar_with_neighborhood([0, 0], [19, 10], &Neighborhood::eight_connected(), weight_function, heuristic).unwrap();
+		println!("[path]\n{path:?}\n");
+
+		assert_eq!(path.len(), 20);
+		assert_eq!(path[0], [0, 0]);
+		assert_eq!(path[19], [19, 10]);
+		assert_eq!(grid.find_path_astar([3, 3], [3, 3], weight_function, PathHeuristic::manhattan).unwrap(), vec![[3, 3]]);
+	}
+
+	#[test]
+	fn test_path_heuristics() {
+		assert_eq!(PathHeuristic::manhattan::<usize>([1, 1], [4, 5]), 7);
+		assert_eq!(PathHeuristic::euclidean::<usize>([1, 1], [4, 5]), 5);
+		assert_eq!(PathHeuristic::chebyshev::<usize>([1, 1], [4, 5]), 4);
+		assert_eq!(PathHeuristic::octile::<usize>([4, 5], [1, 1]), 5);
+		assert_eq!(PathHeuristic::octile::<u32>([0, 0], [10, 10]), 14);
+	}
+	#[test]
+	fn test_cost_field() {
+		let maze:[&str; 4] = [
+			"xxxxx",
+			"x   x",
+			"x xxx",
+			"x x  "
+		];
+
+		let grid:Grid<char> = Grid::new(maze.iter().flat_map(|line| line.chars()).collect(), 5, 4);
+		println!("[grid]\n{grid}\n");
+		let weight_function = |(_left_index, _left):(usize, &char), (_right_index, right):(usize, &char)| if *right == 'x' { Some(1) } else { None };
+		let cost_field:CostField<usize> = grid.cost_field(&[[0, 3], [4, 1]], weight_function);
+		println!("[cost field]\n{}\n", cost_field.costs().map_ref(|cost| cost.map(|cost| cost.to_string()).unwrap_or_default()));
+
+		assert_eq!(cost_field.costs().data_2d(), [
+			[Some(3), Some(4), Some(3), Some(2), Some(1)],
+			[Some(2), None, None, None, Some(0)],
+			[Some(1), None, Some(3), Some(2), Some(1)],
+			[Some(0), None, Some(4), None, None]
+		]);
+		assert_eq!(cost_field.path_to([1, 0]).unwrap(), vec![[0, 3], [0, 2], [0, 1], [0, 0], [1, 0]]);
+		assert_eq!(cost_field.path_to([2, 3]).unwrap(), vec![[4, 1], [4, 2], [3, 2], [2, 2], [2, 3]]);
+		assert_eq!(cost_field.path_to([0, 3]).unwrap(), vec![[0, 3]]);
+		assert!(cost_field.path_to([1, 1]).is_err());
+		assert!(cost_field.path_to([5, 0]).is_err());
+		assert_eq!(cost_field.cost([2, 2]), Some(3));
+		assert_eq!(cost_field.cost([1, 1]), None);
+	}
+
+	#[test]
+	fn test_cost_field_one_way() {
+		let grid:Grid<u8> = Grid::new(vec![0; 4], 2, 2);
+		let weight_function = |(from, _):(usize, &u8), (to, _):(usize, &u8)| match (from, to) {
+			(0, 1) => Some(1),
+			(0, 2) => Some(2),
+			(2, 3) => Some(2),
+			_ => None
+		};
+		let cost_field:CostField<usize> = grid.cost_field(&[[0, 0]], weight_function);
+
+		assert_eq!(cost_field.costs().data_2d(), [[Some(0), Some(1)], [Some(2), Some(4)]]);
+		assert!(std::ptr::eq(cost_field.costs(), cost_field.costs()));
+		assert_eq!(cost_field.cost([1, 1]), Some(4));
+		assert_eq!(cost_field.path_to([1, 1]).unwrap(), vec![[0, 0], [0, 1], [1, 1]]);
+		assert_eq!(cost_field.path_to([1, 0]).unwrap(), vec![[0, 0], [1, 0]]);
+	}
+
+	#[test]
+	fn test_cost_field_zero_weights() {
+		let grid:Grid<u8> = Grid::new(vec![0; 5], 5, 1);
+		let cost_field:CostField<usize> = grid.cost_field(&[[0, 0]], |_, _| Some(0));
+
+		assert_eq!(cost_field.costs().data(), &[Some(0); 5]);
+		assert_eq!(cost_field.path_to([4, 0]).unwrap(), vec![[0, 0], [1, 0], [2, 0], [3, 0], [4, 0]]);
+	}
 }
\ No newline at end of file
@@ -0,0 +1,225 @@
+use crate::{ CellComparator, Grid, GridError, GridMask };
+
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct PoseSearch {
+	angles:Vec<f32>, // Clockwise rotations in degrees.
+	scales:Vec<f32>,
+	min_compared_share:f32 // Share of the cells compared at the original pose that a pose needs to compare.
+}
+impl PoseSearch {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new pose search that only tries the original orientation and scale.
+	pub fn new() -> PoseSearch {
+		PoseSearch {
+			angles: vec![0.0],
+			scales: vec![1.0],
+			min_compared_share: 0.1
+		}
+	}
+
+	/// Return self trying the given clockwise rotations in degrees.
+	pub fn with_angles(mut self, angles:Vec<f32>) -> Self {
+		self.angles = angles;
+		self
+	}
+
+	/// Return self trying all four 90 degree rotations.
+	pub fn with_right_angles(self) -> Self {
+		self.with_angles(vec![0.0, 90.0, 180.0, 270.0])
+	}
+
+	/// Return self trying a full circle of rotations, starting at 0 and increasing by the given step in degrees.
+	pub fn with_angle_step(self, step:f32) -> Self {
+		assert!(step > 0.0, "Angle step must be positive.");
+		self.with_angles((0..).map(|index| index as f32 * step).take_while(|angle| *angle < 360.0).collect())
+	}
+
+	/// Return self trying the given scales of the template.
+	pub fn with_scales(mut self, scales:Vec<f32>) -> Self {
+		self.scales = scales;
+		self
+	}
+
+	/// Return self skipping poses that compare fewer cells than the given share of the cells compared at the original pose. Small poses compare few cells and easily match by chance. Defaults to 0.1.
+	pub fn with_min_compared_share(mut self, min_compared_share:f32) -> Self {
+		self.min_compared_share = min_compared_share;
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the clockwise rotations in degrees that are tried.
+	pub fn angles(&self) -> &[f32] {
+		&self.angles
+	}
+
+	/// Get the scales that are tried.
+	pub fn scales(&self) -> &[f32] {
+		&self.scales
+	}
+
+	/// Get the share of the cells compared at the original pose that a pose needs to compare.
+	pub fn min_compared_share(&self) -> f32 {
+		self.min_compared_share
+	}
+}
+impl Default for PoseSearch {
+	fn default() -> Self {
+		PoseSearch::new()
+	}
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PoseMatch {
+	pub position:[usize; 2], // Top-left position of the bounds of the transformed template.
+	pub angle:f32,
+	pub scale:f32,
+	pub similarity:f32
+}
+
+
+
+impl<T> Grid<T> where T:Clone + PartialEq {
+
+	/* POSE FINDING METHODS */
+
+	/// Find the pose of the template that best matches self, trying all rotations and scales of the search. Cells outside of the rotated template are not compared. Returns None if no pose reaches the given threshold or the template cannot be searched for, use `try_find_pose` to get the reason.
+	pub fn find_pose(&self, template:&Grid<T>, similarity_threshold_factor:f32, search:&PoseSearch) -> Option<PoseMatch> {
+		self.try_find_pose(template, similarity_threshold_factor, search).unwrap_or_default()
+	}
+
+	/// Find the pose of the template that best matches self, trying all rotations and scales of the search. Cells outside of the rotated template are not compared. Returns None if no pose reaches the given threshold. Poses that do not fit in self are skipped.
+	pub fn try_find_pose(&self, template:&Grid<T>, similarity_threshold_factor:f32, search:&PoseSearch) -> Result<Option<PoseMatch>, GridError> {
+		self.try_find_pose_by(template, similarity_threshold_factor, search, &|left:&T, right:&T| left == right)
+	}
+
+	/// Find the pose of the template that best matches self, only matching the positive pixels of the given mask. Returns None if no pose reaches the given threshold or the template cannot be searched for, use `try_find_pose_masked` to get the reason.
+	pub fn find_pose_masked(&self, template:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, search:&PoseSearch) -> Option<PoseMatch> {
+		self.try_find_pose_masked(template, mask, similarity_threshold_factor, search).unwrap_or_default()
+	}
+
+	/// Find the pose of the template that best matches self, only matching the positive pixels of the given mask. Returns None if no pose reaches the given threshold. Returns an error if the mask is not the size of the template.
+	pub fn try_find_pose_masked(&self, template:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, search:&PoseSearch) -> Result<Option<PoseMatch>, GridError> {
+		self.try_find_pose_masked_by(template, mask, similarity_threshold_factor, search, &|left:&T, right:&T| left == right)
+	}
+}
+impl<T> Grid<T> where T:Clone {
+
+	/* COMPARATOR POSE FINDING METHODS */
+
+	/// Find the pose of the template that best matches self, using the comparator to decide if two cells match. Returns None if no pose reaches the given threshold or the template cannot be searched for, use `try_find_pose_by` to get the reason.
+	pub fn find_pose_by<U>(&self, template:&Grid<T>, similarity_threshold_factor:f32, search:&PoseSearch, comparator:&U) -> Option<PoseMatch> where U:CellComparator<T> + ?Sized {
+		self.try_find_pose_by(template, similarity_threshold_factor, search, comparator).unwrap_or_default()
+	}
+
+	/// Find the pose of the template that best matches self, using the comparator to decide if two cells match. Returns None if no pose reaches the given threshold. Poses that do not fit in self are skipped.
+	pub fn try_find_pose_by<U>(&self, template:&Grid<T>, similarity_threshold_factor:f32, search:&PoseSearch, comparator:&U) -> Result<Option<PoseMatch>, GridError> where U:CellComparator<T> + ?Sized {
+		self.pose_search(template, None, similarity_threshold_factor, search, comparator)
+	}
+
+	/// Find the pose of the template that best matches self, using the comparator to decide if two cells match and only matching the positive pixels of the given mask. Returns None if no pose reaches the given threshold or the template cannot be searched for, use `try_find_pose_masked_by` to get the reason.
+	pub fn find_pose_masked_by<U>(&self, template:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, search:&PoseSearch, comparator:&U) -> Option<PoseMatch> where U:CellComparator<T> + ?Sized {
+		self.try_find_pose_masked_by(template, mask, similarity_threshold_factor, search, comparator).unwrap_or_default()
+	}
+
+	/// Find the pose of the template that best matches self, using the comparator to decide if two cells match and only matching the positive pixels of the given mask. Returns None if no pose reaches the given threshold. Returns an error if the mask is not the size of the template.
+	pub fn try_find_pose_masked_by<U>(&self, template:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, search:&PoseSearch, comparator:&U) -> Result<Option<PoseMatch>, GridError> where U:CellComparator<T> + ?Sized {
+		self.pose_search(template, Some(mask), similarity_threshold_factor, search, comparator)
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Try all poses of the template and keep the best match. Poses comparing fewer cells than the minimum share of the search are skipped. Of equally similar matches, the one comparing the most cells is preferred, after which the order of the angles, the scales and the scanning order decide.
+	fn pose_search<U>(&self, template:&Grid<T>, mask:Option<&GridMask>, similarity_threshold_factor:f32, search:&PoseSearch, comparator:&U) -> Result<Option<PoseMatch>, GridError> where U:CellComparator<T> + ?Sized {
+
+		// Validate sizes.
+		if let Some(mask) = mask && [mask.width(), mask.height()] != [template.width, template.height] {
+			return Err(GridError::SizeMismatch { expected: [template.width, template.height], found: [mask.width(), mask.height()] });
+		}
+		if template.is_empty() {
+			return Ok(None);
+		}
+		let template_pixel_count:usize = mask.map(|mask| mask.positive_ranges().iter().map(|range| range.end - range.start).sum()).unwrap_or(template.width * template.height);
+		let min_comparing_pixel_count:f32 = search.min_compared_share * template_pixel_count as f32;
+
+		// Try each pose.
+		let mut best:Option<(PoseMatch, usize)> = None;
+		for angle in &search.angles {
+			for scale in &search.scales {
+				let (posed_template, posed_mask) = template.posed(mask.map(|mask| mask.grid()), *angle, *scale);
+				if posed_template.width > self.width || posed_template.height > self.height {
+					continue;
+				}
+				let posed_mask:GridMask = GridMask::new(posed_mask);
+				let comparing_pixel_count:usize = posed_mask.positive_ranges().iter().map(|range| range.end - range.start).sum();
+				if comparing_pixel_count == 0 || (comparing_pixel_count as f32) < min_comparing_pixel_count {
+					continue;
+				}
+
+				// Score each position.
+				let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
+				let self_row_shift:usize = self.width - posed_template.width;
+				for origin_y in 0..self.height - posed_template.height + 1 {
+					for origin_x in 0..self.width - posed_template.width + 1 {
+						if let Some(mismatches) = self.mismatches_at_position_masked(&posed_template, &posed_mask, max_mismatches, [origin_x, origin_y], self_row_shift, comparator) {
+							let similarity:f32 = 1.0 - mismatches as f32 / comparing_pixel_count as f32;
+							if best.is_none_or(|(best, best_pixel_count)| similarity > best.similarity || (similarity == best.similarity && comparing_pixel_count > best_pixel_count)) {
+								best = Some((PoseMatch { position: [origin_x, origin_y], angle: *angle, scale: *scale, similarity }, comparing_pixel_count));
+							}
+						}
+					}
+				}
+			}
+		}
+		Ok(best.map(|(pose, _)| pose))
+	}
+
+	/// Rotate the grid clockwise by the given angle in degrees and scale it using nearest-neighbor sampling. Returns the transformed grid and a grid that is positive where the transformed grid holds a cell of the original grid that is positive in the given selection.
+	pub(crate) fn posed(&self, selection:Option<&Grid<bool>>, angle:f32, scale:f32) -> (Grid<T>, Grid<bool>) {
+
+		// Right angles are snapped to prevent rounding errors.
+		let normalized_angle:f32 = angle.rem_euclid(360.0);
+		let (sin, cos) = match normalized_angle {
+			0.0 => (0.0, 1.0),
+			90.0 => (1.0, 0.0),
+			180.0 => (0.0, -1.0),
+			270.0 => (-1.0, 0.0),
+			_ => normalized_angle.to_radians().sin_cos()
+		};
+
+		// Calculate the bounds of the transformed grid.
+		let width:usize = ((self.width as f32 * cos.abs() + self.height as f32 * sin.abs()) * scale).round().max(1.0) as usize;
+		let height:usize = ((self.width as f32 * sin.abs() + self.height as f32 * cos.abs()) * scale).round().max(1.0) as usize;
+
+		// Sample the source cell for each cell by reversing the transformation around the centers.
+		let mut data:Vec<T> = Vec::with_capacity(width * height);
+		let mut selected:Vec<bool> = Vec::with_capacity(width * height);
+		for y in 0..height {
+			for x in 0..width {
+				let offset_x:f32 = (x as f32 + 0.5 - width as f32 / 2.0) / scale;
+				let offset_y:f32 = (y as f32 + 0.5 - height as f32 / 2.0) / scale;
+				let source_x:f32 = (offset_x * cos + offset_y * sin + self.width as f32 / 2.0).floor();
+				let source_y:f32 = (offset_y * cos - offset_x * sin + self.height as f32 / 2.0).floor();
+				if source_x >= 0.0 && source_y >= 0.0 && (source_x as usize) < self.width && (source_y as usize) < self.height {
+					let source_index:usize = source_y as usize * self.width + source_x as usize;
+					data.push(self.data[source_index].clone());
+					selected.push(selection.is_none_or(|selection| selection.data[source_index]));
+				} else {
+					data.push(self.data[0].clone());
+					selected.push(false);
+				}
+			}
+		}
+		(Grid::new(data, width, height), Grid::new(selected, width, height))
+	}
+}
\ No newline at end of file
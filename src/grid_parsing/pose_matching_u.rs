@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod test {
+	use crate::{ Grid, GridError, GridMask, PoseMatch, PoseSearch };
+
+
+
+	fn test_template() -> Grid<u8> {
+		Grid::new(vec![
+			1, 1, 1, 1,
+			1, 2, 2, 0,
+			1, 0, 0, 0
+		], 4, 3)
+	}
+
+	fn test_source(template:&Grid<u8>, position:[usize; 2]) -> Grid<u8> {
+		let mut source:Grid<u8> = Grid::new(vec![5; 12 * 10], 12, 10);
+		for y in 0..template.height {
+			for x in 0..template.width {
+				source[(position[0] + x, position[1] + y)] = template[(x, y)];
+			}
+		}
+		source
+	}
+
+
+
+	#[test]
+	fn test_find_pose_right_angles() {
+		let template:Grid<u8> = test_template();
+		let source:Grid<u8> = test_source(&template.rotated_90(), [6, 2]);
+		println!("[source]\n{source}\n");
+
+		assert_eq!(source.find_pose(&template, 1.0, &PoseSearch::new()), None);
+		assert_eq!(source.find_pose(&template, 1.0, &PoseSearch::new().with_right_angles()), Some(PoseMatch { position: [6, 2], angle: 90.0, scale: 1.0, similarity: 1.0 }));
+
+		let source:Grid<u8> = test_source(&template.rotated_270(), [0, 6]);
+		assert_eq!(source.find_pose(&template, 0.9, &PoseSearch::new().with_angle_step(45.0)).map(|pose| (pose.position, pose.angle)), Some(([0, 6], 270.0)));
+	}
+
+	#[test]
+	fn test_find_pose_scaled() {
+		let template:Grid<u8> = test_template();
+		let source:Grid<u8> = test_source(&template.resized_nearest(8, 6), [3, 1]);
+		println!("[source]\n{source}\n");
+
+		let search:PoseSearch = PoseSearch::new().with_right_angles().with_scales(vec![0.5, 1.0, 2.0]);
+		assert_eq!(source.find_pose(&template, 1.0, &search), Some(PoseMatch { position: [3, 1], angle: 0.0, scale: 2.0, similarity: 1.0 }));
+		assert_eq!(search.angles(), &[0.0, 90.0, 180.0, 270.0]);
+		assert_eq!(search.scales(), &[0.5, 1.0, 2.0]);
+	}
+
+	#[test]
+	fn test_find_pose_arbitrary_angle() {
+		let template:Grid<u8> = Grid::new((0..7 * 5).map(|index| (index * index % 11) as u8).collect(), 7, 5);
+		let (posed_template, posed_selection) = template.posed(None, 30.0, 1.0);
+		let mut source:Grid<u8> = Grid::new(vec![20; 16 * 14], 16, 14);
+		for (index, value) in posed_template.data.iter().enumerate() {
+			if posed_selection.data[index] {
+				source[(5 + index % posed_template.width, 3 + index / posed_template.width)] = *value;
+			}
+		}
+		println!("[posed template]\n{posed_template}\n");
+		println!("[source]\n{source}\n");
+
+		assert_eq!(source.find_pose(&template, 1.0, &PoseSearch::new()), None);
+		assert_eq!(source.find_pose(&template, 0.8, &PoseSearch::new().with_angle_step(15.0)), Some(PoseMatch { position: [5, 3], angle: 30.0, scale: 1.0, similarity: 1.0 }));
+	}
+
+	#[test]
+	fn test_find_pose_noisy_template() {
+		let template:Grid<u8> = Grid::new((0..10 * 8).map(|index| (index * index % 7) as u8).collect(), 10, 8);
+		let mut source:Grid<u8> = test_source(&template, [1, 1]);
+		source[(5, 4)] = 9;
+		println!("[source]\n{source}\n");
+
+		let search:PoseSearch = PoseSearch::new().with_scales(vec![0.1, 1.0]);
+		assert_eq!(search.min_compared_share(), 0.1);
+		assert_eq!(source.find_pose(&template, 0.9, &search), Some(PoseMatch { position: [1, 1], angle: 0.0, scale: 1.0, similarity: 0.9875 }));
+		assert_eq!(source.find_pose(&template, 0.9, &search.with_min_compared_share(0.0)).map(|pose| (pose.scale, pose.similarity)), Some((0.1, 1.0)));
+	}
+
+	#[test]
+	fn test_find_pose_masked() {
+		let template:Grid<u8> = test_template();
+		let mut source:Grid<u8> = test_source(&template.rotated_180(), [2, 4]);
+		source[(2, 4)] = 9;
+		let mut mask_grid:Grid<bool> = Grid::new(vec![true; 12], 4, 3);
+		mask_grid[(3, 2)] = false;
+		let mask:GridMask = GridMask::new(mask_grid);
+		let search:PoseSearch = PoseSearch::new().with_right_angles();
+
+		assert_eq!(source.find_pose(&template, 1.0, &search), None);
+		assert_eq!(source.find_pose_masked(&template, &mask, 1.0, &search), Some(PoseMatch { position: [2, 4], angle: 180.0, scale: 1.0, similarity: 1.0 }));
+		assert!(matches!(source.try_find_pose_masked(&template, &GridMask::new(Grid::new(vec![true; 4], 2, 2)), 1.0, &search), Err(GridError::SizeMismatch { .. })));
+		assert_eq!(source.find_pose_by(&template, 1.0, &search, &|left:&u8, right:&u8| left == right || *left == 9), source.find_pose_masked(&template, &mask, 1.0, &search));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,277 @@
+use crate::{ CellComparator, Grid, GridError, GridMask, Interpolatable, PoolingMethod };
+
+
+
+const MIN_COARSE_TEMPLATE_SIZE:usize = 2;
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PyramidSearch {
+	max_levels:usize,
+	pooling:PoolingMethod,
+	tolerance:f32,
+	refinement_radius:usize
+}
+impl PyramidSearch {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new pyramid search using at most the given amount of levels, including the full size level.
+	pub fn new(max_levels:usize) -> PyramidSearch {
+		PyramidSearch {
+			max_levels,
+			pooling: PoolingMethod::Average,
+			tolerance: 0.25,
+			refinement_radius: 1
+		}
+	}
+
+	/// Return self with the given pooling method for downsampling.
+	pub fn with_pooling(mut self, pooling:PoolingMethod) -> Self {
+		self.pooling = pooling;
+		self
+	}
+
+	/// Return self with the given tolerance. Candidates on downsampled levels only need to reach the similarity threshold minus the tolerance. A higher tolerance keeps more candidates, making the search slower but more likely to give the same result as an exhaustive search. A tolerance of 1.0 or more always gives the same result.
+	pub fn with_tolerance(mut self, tolerance:f32) -> Self {
+		self.tolerance = tolerance;
+		self
+	}
+
+	/// Return self with the given refinement radius. Each candidate is also checked at the full size positions within the radius around it on every larger level.
+	pub fn with_refinement_radius(mut self, refinement_radius:usize) -> Self {
+		self.refinement_radius = refinement_radius;
+		self
+	}
+
+
+
+	/* PROPERTY GETTER METHODS */
+
+	/// Get the maximum amount of levels, including the full size level.
+	pub fn max_levels(&self) -> usize {
+		self.max_levels
+	}
+
+	/// Get the pooling method used for downsampling.
+	pub fn pooling(&self) -> PoolingMethod {
+		self.pooling
+	}
+
+	/// Get the similarity tolerance on downsampled levels.
+	pub fn tolerance(&self) -> f32 {
+		self.tolerance
+	}
+
+	/// Get the radius of full size positions around candidates checked on every larger level.
+	pub fn refinement_radius(&self) -> usize {
+		self.refinement_radius
+	}
+}
+impl Default for PyramidSearch {
+	fn default() -> Self {
+		PyramidSearch::new(3)
+	}
+}
+
+
+
+impl<T> Grid<T> where T:Interpolatable + PartialEq {
+
+	/* PYRAMID FINDING METHODS */
+
+	/// Find the given sub-grid in self by searching downsampled copies first and refining the candidates on each larger level. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Returns None if the sub-grid cannot be searched for, use `try_find_pyramid` to get the reason.
+	pub fn find_pyramid(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, search:&PyramidSearch) -> Option<[usize; 2]> {
+		self.try_find_pyramid(sub_grid, similarity_threshold_factor, search).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self by searching downsampled copies first and refining the candidates on each larger level. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Returns an error if the sub-grid is larger than self.
+	pub fn try_find_pyramid(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, search:&PyramidSearch) -> Result<Option<[usize; 2]>, GridError> {
+		self.pyramid_search(sub_grid, None, similarity_threshold_factor, search, &|left:&T, right:&T| left == right)
+	}
+
+	/// Find the given sub-grid in self by searching downsampled copies first, only matching the positive pixels of the given mask. Returns None if the sub-grid cannot be searched for, use `try_find_pyramid_masked` to get the reason.
+	pub fn find_pyramid_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, search:&PyramidSearch) -> Option<[usize; 2]> {
+		self.try_find_pyramid_masked(sub_grid, mask, similarity_threshold_factor, search).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self by searching downsampled copies first, only matching the positive pixels of the given mask. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_pyramid_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, search:&PyramidSearch) -> Result<Option<[usize; 2]>, GridError> {
+		self.pyramid_search(sub_grid, Some(mask), similarity_threshold_factor, search, &|left:&T, right:&T| left == right)
+	}
+
+	/// Find the given sub-grid in self by searching downsampled copies first, using the comparator to decide if two cells match. Returns None if the sub-grid cannot be searched for, use `try_find_pyramid_by` to get the reason.
+	pub fn find_pyramid_by<U>(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, search:&PyramidSearch, comparator:&U) -> Option<[usize; 2]> where U:CellComparator<T> + ?Sized {
+		self.try_find_pyramid_by(sub_grid, similarity_threshold_factor, search, comparator).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self by searching downsampled copies first, using the comparator to decide if two cells match. Returns an error if the sub-grid is larger than self.
+	pub fn try_find_pyramid_by<U>(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, search:&PyramidSearch, comparator:&U) -> Result<Option<[usize; 2]>, GridError> where U:CellComparator<T> + ?Sized {
+		self.pyramid_search(sub_grid, None, similarity_threshold_factor, search, comparator)
+	}
+
+	/// Find the given sub-grid in self by searching downsampled copies first, using the comparator to decide if two cells match and only matching the positive pixels of the given mask. Returns None if the sub-grid cannot be searched for, use `try_find_pyramid_masked_by` to get the reason.
+	pub fn find_pyramid_masked_by<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, search:&PyramidSearch, comparator:&U) -> Option<[usize; 2]> where U:CellComparator<T> + ?Sized {
+		self.try_find_pyramid_masked_by(sub_grid, mask, similarity_threshold_factor, search, comparator).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self by searching downsampled copies first, using the comparator to decide if two cells match and only matching the positive pixels of the given mask. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_pyramid_masked_by<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, search:&PyramidSearch, comparator:&U) -> Result<Option<[usize; 2]>, GridError> where U:CellComparator<T> + ?Sized {
+		self.pyramid_search(sub_grid, Some(mask), similarity_threshold_factor, search, comparator)
+	}
+
+
+
+	/* HELPER METHODS */
+
+	/// Search for the sub-grid from the smallest pyramid level to the full size level. Candidates are kept as full size positions, each level compares the sub-grid downsampled at the alignment of the candidate to the blocks of that level.
+	fn pyramid_search<U>(&self, sub_grid:&Grid<T>, mask:Option<&GridMask>, similarity_threshold_factor:f32, search:&PyramidSearch, comparator:&U) -> Result<Option<[usize; 2]>, GridError> where U:CellComparator<T> + ?Sized {
+
+		// Validate sizes.
+		if self.width < sub_grid.width || self.height < sub_grid.height {
+			return Err(GridError::SubGridTooLarge { grid: [self.width, self.height], sub_grid: [sub_grid.width, sub_grid.height] });
+		}
+		if let Some(mask) = mask && [mask.width(), mask.height()] != [sub_grid.width, sub_grid.height] {
+			return Err(GridError::SizeMismatch { expected: [sub_grid.width, sub_grid.height], found: [mask.width(), mask.height()] });
+		}
+
+		// Build coarse levels until the sub-grid would become too small at any alignment. Aligning crops less than a block from each side.
+		let mut levels:Vec<PyramidLevel<T>> = Vec::new();
+		while levels.len() + 1 < search.max_levels {
+			let scale:usize = 2 << levels.len();
+			if (sub_grid.width + 1) / scale <= MIN_COARSE_TEMPLATE_SIZE || (sub_grid.height + 1) / scale <= MIN_COARSE_TEMPLATE_SIZE {
+				break;
+			}
+			let source:Grid<T> = levels.last().map(|level| &level.source).unwrap_or(self).downsampled(search.pooling);
+			levels.push(PyramidLevel::new(source, sub_grid, mask, scale, search.pooling));
+		}
+
+		// Find all candidates on the smallest level, trying every alignment of the sub-grid.
+		let max_position:[usize; 2] = [self.width - sub_grid.width, self.height - sub_grid.height];
+		let coarse_threshold:f32 = similarity_threshold_factor - search.tolerance;
+		let mut candidates:Vec<[usize; 2]> = match levels.last() {
+			Some(level) => level.candidates(max_position, coarse_threshold, comparator),
+			None => (0..=max_position[1]).flat_map(|y| (0..=max_position[0]).map(move |x| [x, y])).collect()
+		};
+
+		// Refine candidates on each larger level.
+		let radius:usize = search.refinement_radius;
+		for level_index in (0..levels.len().saturating_sub(1)).rev() {
+			candidates = refined_candidates(&candidates, radius, max_position).into_iter().filter(|position| levels[level_index].matches_at(*position, coarse_threshold, comparator)).collect();
+		}
+		if !levels.is_empty() {
+			candidates = refined_candidates(&candidates, radius, max_position);
+		}
+
+		// Candidates are sorted in scanning order.
+		Ok(candidates.into_iter().find(|position| self.pyramid_level_matches(sub_grid, mask, similarity_threshold_factor, *position, comparator)))
+	}
+
+	/// Check if the template matches self at the given position on a pyramid level.
+	fn pyramid_level_matches<U>(&self, template:&Grid<T>, mask:Option<&GridMask>, similarity_threshold_factor:f32, position:[usize; 2], comparator:&U) -> bool where U:CellComparator<T> + ?Sized {
+		let self_row_shift:usize = self.width - template.width;
+		match mask {
+			Some(mask) => {
+				let comparing_pixel_count:usize = mask.positive_ranges().iter().map(|range| range.end - range.start).sum();
+				let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
+				self.mismatches_at_position_masked(template, mask, max_mismatches, position, self_row_shift, comparator).is_some()
+			},
+			None => {
+				let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * (template.width * template.height) as f32).round() as usize;
+				self.mismatches_at_position(template, max_mismatches, position, self_row_shift, comparator).is_some()
+			}
+		}
+	}
+}
+
+
+
+struct PyramidLevel<T> {
+	source:Grid<T>,
+	scale:usize, // Amount of full size cells along each axis in a cell of this level.
+	alignments:Vec<(Grid<T>, Option<GridMask>)> // The downsampled sub-grid and mask for each full size position modulo the scale, indexed as `y * scale + x`.
+}
+impl<T> PyramidLevel<T> where T:Interpolatable + PartialEq {
+
+	/// Create a new level from the downsampled source, downsampling the sub-grid at every alignment to the blocks of the level.
+	fn new(source:Grid<T>, sub_grid:&Grid<T>, mask:Option<&GridMask>, scale:usize, pooling:PoolingMethod) -> PyramidLevel<T> {
+		let alignments:Vec<(Grid<T>, Option<GridMask>)> = (0..scale * scale).map(|offset_index| {
+
+			// Crop the sub-grid to the cells covering entire blocks.
+			let crop:[usize; 2] = Self::crop([offset_index % scale, offset_index / scale], scale);
+			let bounds:[usize; 4] = [crop[0], crop[1], (sub_grid.width - crop[0]) / scale * scale, (sub_grid.height - crop[1]) / scale * scale];
+			let mut aligned:Grid<T> = sub_grid.clone().take(bounds);
+			let mut aligned_mask:Option<GridMask> = mask.map(|mask| GridMask::new(mask.grid().clone().take(bounds)));
+
+			// Downsample to the level.
+			let mut aligned_scale:usize = 1;
+			while aligned_scale < scale {
+				aligned = aligned.downsampled(pooling);
+				aligned_mask = aligned_mask.as_ref().map(downsampled_mask);
+				aligned_scale *= 2;
+			}
+			(aligned, aligned_mask)
+		}).collect();
+		PyramidLevel { source, scale, alignments }
+	}
+
+	/// Get the amount of cells to crop from the top-left of the sub-grid for it to start on a block, when placed at a full size position with the given offset modulo the scale.
+	fn crop(offset:[usize; 2], scale:usize) -> [usize; 2] {
+		[(scale - offset[0]) % scale, (scale - offset[1]) % scale]
+	}
+
+	/// Find all full size positions up to the given maximum where the sub-grid matches this level.
+	fn candidates<U>(&self, max_position:[usize; 2], similarity_threshold_factor:f32, comparator:&U) -> Vec<[usize; 2]> where U:CellComparator<T> + ?Sized {
+		let mut candidates:Vec<[usize; 2]> = Vec::new();
+		for (offset_index, (template, mask)) in self.alignments.iter().enumerate() {
+			let crop:[usize; 2] = Self::crop([offset_index % self.scale, offset_index / self.scale], self.scale);
+			for y in 0..self.source.height - template.height + 1 {
+				for x in 0..self.source.width - template.width + 1 {
+					let [block_x, block_y] = [x * self.scale, y * self.scale];
+					if block_x < crop[0] || block_y < crop[1] || block_x - crop[0] > max_position[0] || block_y - crop[1] > max_position[1] {
+						continue;
+					}
+					if self.source.pyramid_level_matches(template, mask.as_ref(), similarity_threshold_factor, [x, y], comparator) {
+						candidates.push([block_x - crop[0], block_y - crop[1]]);
+					}
+				}
+			}
+		}
+		candidates.sort_unstable_by_key(|[x, y]| [*y, *x]);
+		candidates
+	}
+
+	/// Check if the sub-grid placed at the given full size position matches this level.
+	fn matches_at<U>(&self, position:[usize; 2], similarity_threshold_factor:f32, comparator:&U) -> bool where U:CellComparator<T> + ?Sized {
+		let offset:[usize; 2] = [position[0] % self.scale, position[1] % self.scale];
+		let crop:[usize; 2] = Self::crop(offset, self.scale);
+		let (template, mask) = &self.alignments[offset[1] * self.scale + offset[0]];
+		self.source.pyramid_level_matches(template, mask.as_ref(), similarity_threshold_factor, [(position[0] + crop[0]) / self.scale, (position[1] + crop[1]) / self.scale], comparator)
+	}
+}
+
+
+
+/// Get the given full size candidates and all positions within the radius around them up to the maximum position, sorted in scanning order.
+fn refined_candidates(candidates:&[[usize; 2]], radius:usize, max_position:[usize; 2]) -> Vec<[usize; 2]> {
+	let mut refined:Vec<[usize; 2]> = candidates.iter().flat_map(|[x, y]| {
+		(y.saturating_sub(radius)..=(y + radius).min(max_position[1])).flat_map(move |y| (x.saturating_sub(radius)..=(x + radius).min(max_position[0])).map(move |x| [x, y]))
+	}).collect();
+	refined.sort_unstable_by_key(|[x, y]| [*y, *x]);
+	refined.dedup();
+	refined
+}
+
+
+
+/// Downsample a mask to half the size. A cell is only positive if all cells in its 2x2 block are positive.
+fn downsampled_mask(mask:&GridMask) -> GridMask {
+	let source:&Grid<bool> = mask.grid();
+	let width:usize = source.width.div_ceil(2);
+	let height:usize = source.height.div_ceil(2);
+	let data:Vec<bool> = (0..height).flat_map(|y| (0..width).map(move |x| [y, x])).map(|[y, x]| {
+		(y * 2..(y * 2 + 2).min(source.height)).all(|source_y| (x * 2..(x * 2 + 2).min(source.width)).all(|source_x| source.data[source_y * source.width + source_x]))
+	}).collect();
+	GridMask::new(Grid::new(data, width, height))
+}
\ No newline at end of file
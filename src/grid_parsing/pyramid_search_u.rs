@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod test {
+	use crate::{ Grid, GridError, GridMask, PoolingMethod, PyramidSearch };
+
+
+
+	fn test_source() -> Grid<u8> {
+		Grid::new((0..64 * 48).map(|index:u64| ((index * index * 7919 + index * 104_729) % 1_000_003 % 4) as u8).collect(), 64, 48)
+	}
+
+
+
+	#[test]
+	fn test_find_pyramid_matches_exhaustive() {
+		let source:Grid<u8> = test_source();
+		for (position, size) in [([0, 0], [8, 8]), ([37, 21], [9, 7]), ([50, 39], [14, 9]), ([12, 5], [5, 5])] {
+			let sub_grid:Grid<u8> = source.clone().take([position[0], position[1], size[0], size[1]]);
+			let exhaustive:Option<[usize; 2]> = source.find(&sub_grid, 1.0);
+			for search in [PyramidSearch::default(), PyramidSearch::new(4).with_pooling(PoolingMethod::Mode), PyramidSearch::new(3).with_tolerance(1.0)] {
+				assert_eq!(source.find_pyramid(&sub_grid, 1.0, &search), exhaustive);
+			}
+			assert_eq!(exhaustive, Some(position));
+		}
+	}
+
+	#[test]
+	fn test_find_pyramid_odd_offsets() {
+		let mut source:Grid<u8> = test_source();
+		for (position, size) in [([37, 21], [13, 11]), ([50, 35], [11, 13]), ([13, 5], [24, 16]), ([7, 29], [23, 15])] {
+			let sub_grid:Grid<u8> = source.clone().take([position[0], position[1], size[0], size[1]]);
+			source[(position[0] + 3, position[1] + 2)] = 9;
+			for search in [PyramidSearch::default(), PyramidSearch::new(4).with_pooling(PoolingMethod::Mode)] {
+				assert_eq!(source.find_pyramid(&sub_grid, 0.99, &search), source.find(&sub_grid, 0.99));
+				assert_eq!(source.find_pyramid(&sub_grid, 0.99, &search), Some(position));
+				assert_eq!(source.find_pyramid(&sub_grid, 1.0, &search), None);
+			}
+			source[(position[0] + 3, position[1] + 2)] = sub_grid[(3, 2)];
+		}
+	}
+
+	#[test]
+	fn test_find_pyramid_sprite() {
+		let mut source:Grid<u8> = Grid::new(vec![0; 80 * 60], 80, 60);
+		let sprite:Grid<u8> = Grid::new((0..12 * 10).map(|index| if (index / 12 + index % 12) % 5 == 0 { 0 } else { 200 }).collect(), 12, 10);
+		for (index, value) in sprite.data.iter().enumerate() {
+			source[(43 + index % 12, 27 + index / 12)] = *value;
+		}
+		source[(44, 28)] = 90;
+		println!("[sprite]\n{sprite}\n");
+
+		let search:PyramidSearch = PyramidSearch::default().with_pooling(PoolingMethod::Mode).with_tolerance(0.4);
+		assert_eq!(source.find_pyramid(&sprite, 0.95, &search), source.find(&sprite, 0.95));
+		assert_eq!(source.find_pyramid(&sprite, 0.95, &search), Some([43, 27]));
+		assert_eq!(source.find_pyramid(&sprite, 1.0, &search), None);
+		assert_eq!(source.find_pyramid_by(&sprite, 1.0, &search, &|left:&u8, right:&u8| left.abs_diff(*right) <= 110), Some([43, 27]));
+
+		let mut mask_grid:Grid<bool> = Grid::new(vec![true; 12 * 10], 12, 10);
+		mask_grid[(1, 1)] = false;
+		let mask:GridMask = GridMask::new(mask_grid);
+		assert_eq!(source.find_pyramid_masked(&sprite, &mask, 1.0, &search), source.find_masked(&sprite, &mask, 1.0));
+		assert_eq!(source.find_pyramid_masked(&sprite, &mask, 1.0, &search), Some([43, 27]));
+	}
+
+	#[test]
+	fn test_find_pyramid_errors() {
+		let source:Grid<u8> = test_source();
+		let sub_grid:Grid<u8> = source.clone().take([0, 0, 4, 4]);
+		let mask:GridMask = GridMask::new(Grid::new(vec![true; 9], 3, 3));
+
+		assert!(matches!(sub_grid.try_find_pyramid(&source, 1.0, &PyramidSearch::default()), Err(GridError::SubGridTooLarge { .. })));
+		assert!(matches!(source.try_find_pyramid_masked(&sub_grid, &mask, 1.0, &PyramidSearch::default()), Err(GridError::SizeMismatch { .. })));
+	}
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::ops::{ Index, IndexMut, Range };
-use crate::{ Grid, GridIndexer };
+use crate::{ DistanceTarget, Grid, GridIndexer, GridView, Neighborhood };
 
 
 
@@ -105,20 +105,8 @@ impl GridRegion {
 	}
 
 	/// Map the region to values indicating their distance to the nearest edge.
-	pub fn to_edge_distance_map(mut self) -> Grid<usize> {
-		let mut edge_map:Grid<usize> = Grid::new(vec![0; self.grid.width * self.grid.height], self.grid.width, self.grid.height);
-		let mut distance:usize = 1;
-		let mut any_changes:bool = true;
-		while any_changes {
-			any_changes = false;
-			for edge_index in self.find_edges().into_iter().map(|edge| edge.positive_index) {
-				self[edge_index] = false;
-				edge_map[edge_index] = distance;
-				any_changes = true;
-			}
-			distance += 1;
-		}
-		edge_map
+	pub fn to_edge_distance_map(self) -> Grid<usize> {
+		self.manhattan_distance_map(DistanceTarget::Outside)
 	}
 
 	/// Find the edges of the region.
@@ -192,6 +180,41 @@ impl GridRegion {
 		&self.grid
 	}
 
+	/// Get the bounds of the positive pixels in the region. Bounds are XYWH.
+	pub fn bounds(&self) -> [usize; 4] {
+		self.bounds
+	}
+
+	/// Get the amount of positive pixels in the region.
+	pub fn area(&self) -> usize {
+		let mut area:usize = 0;
+		for y in self.bounds[1]..self.bounds[1] + self.bounds[3] {
+			let y_index:usize = y * self.grid.width;
+			area += self.grid.data[y_index + self.bounds[0]..y_index + self.bounds[0] + self.bounds[2]].iter().filter(|value| **value).count();
+		}
+		area
+	}
+
+	/// Get the average X and Y coordinate of the positive pixels in the region. Returns None if the region is empty.
+	pub fn centroid(&self) -> Option<[f32; 2]> {
+		let mut sum:[usize; 2] = [0, 0];
+		let mut area:usize = 0;
+		for y in self.bounds[1]..self.bounds[1] + self.bounds[3] {
+			for x in self.bounds[0]..self.bounds[0] + self.bounds[2] {
+				if self.grid.data[y * self.grid.width + x] {
+					sum[0] += x;
+					sum[1] += y;
+					area += 1;
+				}
+			}
+		}
+		if area == 0 {
+			None
+		} else {
+			Some([sum[0] as f32 / area as f32, sum[1] as f32 / area as f32])
+		}
+	}
+
 	/// Get the positive indexes of the region.
 	pub fn indexes(&self) -> Vec<usize> {
 		let mut indexes:Vec<usize> = Vec::with_capacity(self.bounds[2] * self.bounds[3]);
@@ -212,6 +235,16 @@ impl GridRegion {
 	pub fn bounds_sub_grid(&self) -> Grid<&bool> {
 		self.grid.sub_grid(self.bounds)
 	}
+
+	/// Get a view of the bounds without copying.
+	pub fn bounds_view(&self) -> GridView<'_, bool> {
+		self.grid.view(self.bounds)
+	}
+}
+impl AsRef<Grid<bool>> for GridRegion {
+	fn as_ref(&self) -> &Grid<bool> {
+		&self.grid
+	}
 }
 impl<U> Index<U> for GridRegion where U:GridIndexer {
 	type Output = bool;
@@ -244,11 +277,14 @@ impl<T> Grid<T> where T:PartialEq {
 
 	/// Starting at the selected pixel, create a list of all attached pixels that match the comparing function. In the comparing function, the first value is the value of the neighbor that added this node to the queue. The second value is the value of the current node.
 	pub fn region_at<U, V>(&self, start:U, comparing_function:V) -> GridRegion where U:GridIndexer, V:Fn(&T, &T) -> bool {
+		self.region_at_with_neighborhood(start, &Neighborhood::four_connected(), comparing_function)
+	}
+
+	/// Starting at the selected pixel, create a list of all pixels attached through the given neighborhood that match the comparing function. In the comparing function, the first value is the value of the neighbor that added this node to the queue. The second value is the value of the current node.
+	pub fn region_at_with_neighborhood<U, V>(&self, start:U, neighborhood:&Neighborhood, comparing_function:V) -> GridRegion where U:GridIndexer, V:Fn(&T, &T) -> bool {
 
 		// Prepare important indexes.
 		let start_index:usize = start.to_grid_index(self);
-		let max_x:usize = self.width - 1;
-		let max_y:usize = self.len() - self.width;
 
 		// Create region tracking grid.
 		let mut region_grid:Grid<bool> = Grid::new(vec![false; self.width * self.height], self.width, self.height);
@@ -272,7 +308,7 @@ impl<T> Grid<T> where T:PartialEq {
 				region_grid[current_index] = true;
 
 				// Add neighbors to queue.
-				for neighbor_index in self._index_neighbors(current_index, max_x, max_y) {
+				for neighbor_index in neighborhood.neighbor_indexes(current_index, self.width, self.height) {
 					if !region_grid[neighbor_index] && !checked_values_grid[neighbor_index].contains(&source_value) {
 						queue.push((neighbor_index, &self[current_index]));
 						checked_values_grid[neighbor_index].push(source_value);
@@ -289,11 +325,14 @@ impl<T> Grid<T> where T:PartialEq {
 
 	/// Starting at the selected pixel, create a list of all attached pixels that are the same. This function is very similar to the `region_at` function. Because this function does not need to check nodes for multiple different values, it is more efficient.
 	pub fn region_at_eq<U>(&self, start:U) -> GridRegion where U:GridIndexer {
+		self.region_at_eq_with_neighborhood(start, &Neighborhood::four_connected())
+	}
+
+	/// Starting at the selected pixel, create a list of all pixels attached through the given neighborhood that are the same.
+	pub fn region_at_eq_with_neighborhood<U>(&self, start:U, neighborhood:&Neighborhood) -> GridRegion where U:GridIndexer {
 		
 		// Prepare important indexes.
 		let start_index:usize = start.to_grid_index(self);
-		let max_x:usize = self.width - 1;
-		let max_y:usize = self.len() - self.width;
 
 		// Create region tracking grid.
 		let mut region_grid:Grid<bool> = Grid::new(vec![false; self.width * self.height], self.width, self.height);
@@ -317,7 +356,7 @@ impl<T> Grid<T> where T:PartialEq {
 				region_grid[current_index] = true;
 
 				// Add neighbors to queue.
-				for neighbor_index in self._index_neighbors(current_index, max_x, max_y) {
+				for neighbor_index in neighborhood.neighbor_indexes(current_index, self.width, self.height) {
 					if !region_grid[neighbor_index] && !queue.contains(&neighbor_index) {
 						queue.push(neighbor_index);
 					}
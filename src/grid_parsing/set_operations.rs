@@ -0,0 +1,198 @@
+use crate::{ Grid, GridError, GridMask, GridRegion };
+
+
+
+impl GridRegion {
+
+	/* SET OPERATION METHODS */
+
+	/// Get a region containing the pixels that are in self, the other region or both. Panics if the regions are not the same size, use `try_union` to handle that case.
+	pub fn union(&self, other:&GridRegion) -> GridRegion {
+		self.try_union(other).unwrap_or_else(|error| panic!("Set operation 'union' failed: {error}"))
+	}
+
+	/// Get a region containing the pixels that are in self, the other region or both. Returns an error if the regions are not the same size.
+	pub fn try_union(&self, other:&GridRegion) -> Result<GridRegion, GridError> {
+		combine_set_grids(&self.grid, &other.grid, |left, right| left || right).map(GridRegion::new)
+	}
+
+	/// Get a region containing the pixels that are in both self and the other region. Panics if the regions are not the same size, use `try_intersect` to handle that case.
+	pub fn intersect(&self, other:&GridRegion) -> GridRegion {
+		self.try_intersect(other).unwrap_or_else(|error| panic!("Set operation 'intersect' failed: {error}"))
+	}
+
+	/// Get a region containing the pixels that are in both self and the other region. Returns an error if the regions are not the same size.
+	pub fn try_intersect(&self, other:&GridRegion) -> Result<GridRegion, GridError> {
+		combine_set_grids(&self.grid, &other.grid, |left, right| left && right).map(GridRegion::new)
+	}
+
+	/// Get a region containing the pixels that are in self, but not in the other region. Panics if the regions are not the same size, use `try_subtract` to handle that case.
+	pub fn subtract(&self, other:&GridRegion) -> GridRegion {
+		self.try_subtract(other).unwrap_or_else(|error| panic!("Set operation 'subtract' failed: {error}"))
+	}
+
+	/// Get a region containing the pixels that are in self, but not in the other region. Returns an error if the regions are not the same size.
+	pub fn try_subtract(&self, other:&GridRegion) -> Result<GridRegion, GridError> {
+		combine_set_grids(&self.grid, &other.grid, |left, right| left && !right).map(GridRegion::new)
+	}
+
+	/// Get a region containing the pixels that are in either self or the other region, but not in both. Panics if the regions are not the same size, use `try_xor` to handle that case.
+	pub fn xor(&self, other:&GridRegion) -> GridRegion {
+		self.try_xor(other).unwrap_or_else(|error| panic!("Set operation 'xor' failed: {error}"))
+	}
+
+	/// Get a region containing the pixels that are in either self or the other region, but not in both. Returns an error if the regions are not the same size.
+	pub fn try_xor(&self, other:&GridRegion) -> Result<GridRegion, GridError> {
+		combine_set_grids(&self.grid, &other.grid, |left, right| left != right).map(GridRegion::new)
+	}
+
+	/// Get a region containing all pixels that are not in self.
+	pub fn invert(&self) -> GridRegion {
+		GridRegion::new(self.grid.map_ref(|value| !*value))
+	}
+
+
+
+	/* CONVERSION METHODS */
+
+	/// Create a mask of the region.
+	pub fn to_mask(&self) -> GridMask {
+		GridMask::new(self.grid.clone())
+	}
+}
+
+
+
+impl GridMask {
+
+	/* SET OPERATION METHODS */
+
+	/// Get a mask containing the pixels that are positive in self, the other mask or both. Panics if the masks are not the same size, use `try_union` to handle that case.
+	pub fn union(&self, other:&GridMask) -> GridMask {
+		self.try_union(other).unwrap_or_else(|error| panic!("Set operation 'union' failed: {error}"))
+	}
+
+	/// Get a mask containing the pixels that are positive in self, the other mask or both. Returns an error if the masks are not the same size.
+	pub fn try_union(&self, other:&GridMask) -> Result<GridMask, GridError> {
+		combine_set_grids(self.grid(), other.grid(), |left, right| left || right).map(GridMask::new)
+	}
+
+	/// Get a mask containing the pixels that are positive in both self and the other mask. Panics if the masks are not the same size, use `try_intersect` to handle that case.
+	pub fn intersect(&self, other:&GridMask) -> GridMask {
+		self.try_intersect(other).unwrap_or_else(|error| panic!("Set operation 'intersect' failed: {error}"))
+	}
+
+	/// Get a mask containing the pixels that are positive in both self and the other mask. Returns an error if the masks are not the same size.
+	pub fn try_intersect(&self, other:&GridMask) -> Result<GridMask, GridError> {
+		combine_set_grids(self.grid(), other.grid(), |left, right| left && right).map(GridMask::new)
+	}
+
+	/// Get a mask containing the pixels that are positive in self, but not in the other mask. Panics if the masks are not the same size, use `try_subtract` to handle that case.
+	pub fn subtract(&self, other:&GridMask) -> GridMask {
+		self.try_subtract(other).unwrap_or_else(|error| panic!("Set operation 'subtract' failed: {error}"))
+	}
+
+	/// Get a mask containing the pixels that are positive in self, but not in the other mask. Returns an error if the masks are not the same size.
+	pub fn try_subtract(&self, other:&GridMask) -> Result<GridMask, GridError> {
+		combine_set_grids(self.grid(), other.grid(), |left, right| left && !right).map(GridMask::new)
+	}
+
+	/// Get a mask containing the pixels that are positive in either self or the other mask, but not in both. Panics if the masks are not the same size, use `try_xor` to handle that case.
+	pub fn xor(&self, other:&GridMask) -> GridMask {
+		self.try_xor(other).unwrap_or_else(|error| panic!("Set operation 'xor' failed: {error}"))
+	}
+
+	/// Get a mask containing the pixels that are positive in either self or the other mask, but not in both. Returns an error if the masks are not the same size.
+	pub fn try_xor(&self, other:&GridMask) -> Result<GridMask, GridError> {
+		combine_set_grids(self.grid(), other.grid(), |left, right| left != right).map(GridMask::new)
+	}
+
+	/// Get a mask where all positive pixels of self are negative and the other way around.
+	pub fn invert(&self) -> GridMask {
+		GridMask::new(self.grid().map_ref(|value| !*value))
+	}
+
+
+
+	/* CONVERSION METHODS */
+
+	/// Create a region of the positive pixels of the mask.
+	pub fn to_region(&self) -> GridRegion {
+		GridRegion::new(self.grid().clone())
+	}
+}
+
+
+
+/// Combine two grids of the same size pixel by pixel. Returns an error if the grids are not the same size.
+fn combine_set_grids<U>(left:&Grid<bool>, right:&Grid<bool>, combine_function:U) -> Result<Grid<bool>, GridError> where U:Fn(bool, bool) -> bool {
+	if [left.width, left.height] != [right.width, right.height] {
+		return Err(GridError::SizeMismatch { expected: [left.width, left.height], found: [right.width, right.height] });
+	}
+	Ok(Grid::new(left.data.iter().zip(&right.data).map(|(left, right)| combine_function(*left, *right)).collect(), left.width, left.height))
+}
+
+
+
+macro_rules! implement_set_operator {
+	($type:ty, $trait:ident, $fn_name:ident, $assign_trait:ident, $assign_fn_name:ident, $method:ident) => {
+		impl std::ops::$trait<&$type> for &$type {
+			type Output = $type;
+
+			fn $fn_name(self, other:&$type) -> Self::Output {
+				self.$method(other)
+			}
+		}
+		impl std::ops::$trait<$type> for $type {
+			type Output = $type;
+
+			fn $fn_name(self, other:$type) -> Self::Output {
+				self.$method(&other)
+			}
+		}
+		impl std::ops::$assign_trait<&$type> for $type {
+			fn $assign_fn_name(&mut self, other:&$type) {
+				*self = self.$method(other);
+			}
+		}
+	};
+}
+implement_set_operator!(GridRegion, BitOr, bitor, BitOrAssign, bitor_assign, union);
+implement_set_operator!(GridRegion, BitAnd, bitand, BitAndAssign, bitand_assign, intersect);
+implement_set_operator!(GridRegion, Sub, sub, SubAssign, sub_assign, subtract);
+implement_set_operator!(GridRegion, BitXor, bitxor, BitXorAssign, bitxor_assign, xor);
+implement_set_operator!(GridMask, BitOr, bitor, BitOrAssign, bitor_assign, union);
+implement_set_operator!(GridMask, BitAnd, bitand, BitAndAssign, bitand_assign, intersect);
+implement_set_operator!(GridMask, Sub, sub, SubAssign, sub_assign, subtract);
+implement_set_operator!(GridMask, BitXor, bitxor, BitXorAssign, bitxor_assign, xor);
+
+
+
+impl std::ops::Not for &GridRegion {
+	type Output = GridRegion;
+
+	fn not(self) -> Self::Output {
+		self.invert()
+	}
+}
+impl std::ops::Not for GridRegion {
+	type Output = GridRegion;
+
+	fn not(self) -> Self::Output {
+		self.invert()
+	}
+}
+impl std::ops::Not for &GridMask {
+	type Output = GridMask;
+
+	fn not(self) -> Self::Output {
+		self.invert()
+	}
+}
+impl std::ops::Not for GridMask {
+	type Output = GridMask;
+
+	fn not(self) -> Self::Output {
+		self.invert()
+	}
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod test {
+	use std::ops::Range;
+	use crate::{ GridError, GridMask, GridRegion, grid_parsing::test_helpers::grid_from_strings };
+
+
+
+	fn region_a() -> GridRegion {
+		GridRegion::new(grid_from_strings(&[
+			"xx  ",
+			"xx  ",
+			"    "
+		]))
+	}
+
+	fn region_b() -> GridRegion {
+		GridRegion::new(grid_from_strings(&[
+			"    ",
+			" xx ",
+			" xx "
+		]))
+	}
+
+
+
+	#[test]
+	fn test_region_set_operations() {
+		let union:GridRegion = region_a().union(&region_b());
+		println!("[union]\n{}\n", union.grid());
+		assert_eq!(union.grid(), &grid_from_strings(&["xx  ", "xxx ", " xx "]));
+		assert_eq!(union.bounds(), [0, 0, 3, 3]);
+
+		let intersection:GridRegion = region_a().intersect(&region_b());
+		assert_eq!(intersection.grid(), &grid_from_strings(&["    ", " x  ", "    "]));
+		assert_eq!(intersection.bounds(), [1, 1, 1, 1]);
+
+		let difference:GridRegion = region_a().subtract(&region_b());
+		assert_eq!(difference.grid(), &grid_from_strings(&["xx  ", "x   ", "    "]));
+		assert_eq!(difference.bounds(), [0, 0, 2, 2]);
+
+		let exclusive:GridRegion = region_a().xor(&region_b());
+		assert_eq!(exclusive.grid(), &grid_from_strings(&["xx  ", "x x ", " xx "]));
+		assert_eq!(exclusive.bounds(), [0, 0, 3, 3]);
+
+		let inverted:GridRegion = region_a().invert();
+		assert_eq!(inverted.grid(), &grid_from_strings(&["  xx", "  xx", "xxxx"]));
+		assert_eq!(inverted.bounds(), [0, 0, 4, 3]);
+		assert_eq!(region_a().subtract(&region_a()).bounds()[2..], [0, 0]);
+	}
+
+	#[test]
+	fn test_region_set_operators() {
+		let mut region:GridRegion = region_a();
+		region |= &region_b();
+		assert_eq!(region.grid(), (&region_a() | &region_b()).grid());
+		region -= &region_b();
+		assert_eq!(region.grid(), (region_a() - region_b()).grid());
+		assert_eq!((region_a() & region_b()).grid(), region_a().intersect(&region_b()).grid());
+		assert_eq!((region_a() ^ region_b()).grid(), region_a().xor(&region_b()).grid());
+		assert_eq!((!region_a()).bounds(), [0, 0, 4, 3]);
+	}
+
+	#[test]
+	fn test_mask_set_operations() {
+		let mask_a:GridMask = region_a().to_mask();
+		let mask_b:GridMask = region_b().to_mask();
+		let union:GridMask = &mask_a | &mask_b;
+		println!("[union]\n{}\n", union.grid());
+
+		assert_eq!(union.positive_ranges(), [0..2, 4..7, 9..11]);
+		assert_eq!(union.negative_ranges(), [2..4, 7..9, 11..12]);
+		assert_eq!(mask_a.intersect(&mask_b).positive_ranges(), [Range { start: 5, end: 6 }]);
+		assert_eq!(mask_a.subtract(&mask_b).positive_ranges(), [0..2, 4..5]);
+		assert_eq!(mask_a.xor(&mask_b).positive_ranges(), [0..2, 4..5, 6..7, 9..11]);
+		assert_eq!((!&mask_a).positive_ranges(), mask_a.negative_ranges());
+		assert_eq!(union.to_region().bounds(), [0, 0, 3, 3]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_set_operation_size_mismatch() {
+		let other:GridRegion = GridRegion::new(grid_from_strings(&["xxx", "xxx", "xxx", "xxx"]));
+		let _ = region_a().union(&other);
+	}
+
+	#[test]
+	fn test_try_set_operations() {
+		let other:GridRegion = GridRegion::new(grid_from_strings(&["xxx", "xxx", "xxx", "xxx"]));
+
+		assert_eq!(region_a().try_union(&region_b()).unwrap().grid(), region_a().union(&region_b()).grid());
+		assert!(matches!(region_a().try_union(&other), Err(GridError::SizeMismatch { expected: [4, 3], found: [3, 4] })));
+		assert!(matches!(region_a().try_intersect(&other), Err(GridError::SizeMismatch { .. })));
+		assert!(matches!(region_a().try_subtract(&other), Err(GridError::SizeMismatch { .. })));
+		assert!(matches!(region_a().try_xor(&other), Err(GridError::SizeMismatch { .. })));
+		assert!(matches!(region_a().to_mask().try_xor(&other.to_mask()), Err(GridError::SizeMismatch { .. })));
+		assert!(region_a().to_mask().try_subtract(&region_b().to_mask()).unwrap() == region_a().to_mask().subtract(&region_b().to_mask()));
+	}
+}
\ No newline at end of file
@@ -7,39 +7,183 @@
 
 
 
-use crate::{ Grid, GridMask };
-use std::error::Error;
+use crate::{ CellComparator, FindOptions, Grid, GridError, GridMask, SimilarityMatch };
 
 
 
 impl<T> Grid<T> where T:PartialEq {
 
+	/* SIMILARITY METHODS */
+
+	/// Compare this grid to another. Returns 'true' if the similarity reaches the given threshold. Returns 'false' if the grids cannot be compared, use `try_similar_to` to get the reason.
+	pub fn similar_to(&self, other:&Grid<T>, similarity_threshold_factor:f32) -> bool {
+		self.try_similar_to(other, similarity_threshold_factor).unwrap_or(false)
+	}
+
+	/// Compare this grid to another. Returns 'true' if the similarity reaches the given threshold. Returns an error if the grids are not the same size.
+	pub fn try_similar_to(&self, other:&Grid<T>, similarity_threshold_factor:f32) -> Result<bool, GridError> {
+		self.try_similar_to_by(other, similarity_threshold_factor, &equal_cells::<T>)
+	}
+
+	/// Compare this grid to another. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. Returns 0.0 if the grids cannot be compared, use `try_similarity_to` to get the reason.
+	pub fn similarity_to(&self, other:&Grid<T>) -> f32 {
+		self.try_similarity_to(other).unwrap_or(0.0)
+	}
+
+	/// Compare this grid to another. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. Returns an error if the grids are not the same size.
+	pub fn try_similarity_to(&self, other:&Grid<T>) -> Result<f32, GridError> {
+		self.try_similarity_to_by(other, &equal_cells::<T>)
+	}
+
+
+
+	/* MASKED SIMILARITY METHODS */
+
+	/// Compare this grid to another. Only compare the pixels matching the given mask. Returns 'true' if the similarity reaches the given threshold. Returns 'false' if the grids cannot be compared, use `try_similar_to_masked` to get the reason.
+	pub fn similar_to_masked(&self, other:&Grid<T>, similarity_threshold_factor:f32, mask:&GridMask) -> bool {
+		self.try_similar_to_masked(other, similarity_threshold_factor, mask).unwrap_or(false)
+	}
+
+	/// Compare this grid to another. Only compare the pixels matching the given mask. Returns 'true' if the similarity reaches the given threshold. Returns an error if the grids and mask are not the same size.
+	pub fn try_similar_to_masked(&self, other:&Grid<T>, similarity_threshold_factor:f32, mask:&GridMask) -> Result<bool, GridError> {
+		self.try_similar_to_masked_by(other, similarity_threshold_factor, mask, &equal_cells::<T>)
+	}
+
+	/// Compare this grid to another. Only compare the pixels matching the given mask. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. Returns 0.0 if the grids cannot be compared, use `try_similarity_to_masked` to get the reason.
+	pub fn similarity_to_masked(&self, other:&Grid<T>, mask:&GridMask) -> f32 {
+		self.try_similarity_to_masked(other, mask).unwrap_or(0.0)
+	}
+
+	/// Compare this grid to another. Only compare the pixels matching the given mask. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. A mask without positive pixels gives a full match. Returns an error if the grids and mask are not the same size.
+	pub fn try_similarity_to_masked(&self, other:&Grid<T>, mask:&GridMask) -> Result<f32, GridError> {
+		self.try_similarity_to_masked_by(other, mask, &equal_cells::<T>)
+	}
+
+
+
+	/* SUB-GRID FINDING METHODS */
+
+	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Returns None if the sub-grid cannot be searched for, use `try_find` to get the reason.
+	pub fn find(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32) -> Option<[usize; 2]> {
+		self.try_find(sub_grid, similarity_threshold_factor).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Returns an error if the sub-grid is larger than self.
+	pub fn try_find(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32) -> Result<Option<[usize; 2]>, GridError> {
+		self.try_find_by(sub_grid, similarity_threshold_factor, &equal_cells::<T>)
+	}
+
+	/// Find all instances of the given sub-grid in self. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold. Returns nothing if the sub-grid cannot be searched for, use `try_find_all` to get the reason.
+	pub fn find_all(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32) -> Vec<[usize; 2]> {
+		self.try_find_all(sub_grid, similarity_threshold_factor).unwrap_or_default()
+	}
+
+	/// Find all instances of the given sub-grid in self. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold. Returns an error if the sub-grid is larger than self.
+	pub fn try_find_all(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32) -> Result<Vec<[usize; 2]>, GridError> {
+		self.try_find_all_by(sub_grid, similarity_threshold_factor, &equal_cells::<T>)
+	}
+
+	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Starts at the given position. Returns None if the sub-grid cannot be searched for, use `try_find_starting_at_position` to get the reason.
+	pub fn find_starting_at_position(&self, sub_grid:&Grid<T>, max_allowed_mismatches:usize, position:[usize; 2]) -> Option<[usize; 2]> {
+		self.try_find_starting_at_position(sub_grid, max_allowed_mismatches, position).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Starts at the given position. Returns an error if the sub-grid is larger than self.
+	pub fn try_find_starting_at_position(&self, sub_grid:&Grid<T>, max_allowed_mismatches:usize, position:[usize; 2]) -> Result<Option<[usize; 2]>, GridError> {
+		self.validate_findable_grids(sub_grid, None)?;
+		Ok(self.scan_starting_at_position(sub_grid, max_allowed_mismatches, position, &equal_cells::<T>))
+	}
+
+
+
+	/* MASKED SUB-GRID FINDING METHODS */
+
+	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Returns None if the sub-grid cannot be searched for, use `try_find_masked` to get the reason.
+	pub fn find_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32) -> Option<[usize; 2]> {
+		self.try_find_masked(sub_grid, mask, similarity_threshold_factor).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32) -> Result<Option<[usize; 2]>, GridError> {
+		self.try_find_masked_by(sub_grid, mask, similarity_threshold_factor, &equal_cells::<T>)
+	}
+
+	/// Find all instances of the given sub-grid in self. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Returns nothing if the sub-grid cannot be searched for, use `try_find_all_masked` to get the reason.
+	pub fn find_all_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32) -> Vec<[usize; 2]> {
+		self.try_find_all_masked(sub_grid, mask, similarity_threshold_factor).unwrap_or_default()
+	}
+
+	/// Find all instances of the given sub-grid in self. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_all_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32) -> Result<Vec<[usize; 2]>, GridError> {
+		self.try_find_all_masked_by(sub_grid, mask, similarity_threshold_factor, &equal_cells::<T>)
+	}
+
+	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Starts at the given position. Returns None if the sub-grid cannot be searched for, use `try_find_starting_at_position_masked` to get the reason.
+	pub fn find_starting_at_position_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, max_allowed_mismatches:usize, position:[usize; 2]) -> Option<[usize; 2]> {
+		self.try_find_starting_at_position_masked(sub_grid, mask, max_allowed_mismatches, position).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Starts at the given position. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_starting_at_position_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, max_allowed_mismatches:usize, position:[usize; 2]) -> Result<Option<[usize; 2]>, GridError> {
+		self.validate_findable_grids(sub_grid, Some(mask))?;
+		Ok(self.scan_starting_at_position_masked(sub_grid, mask, max_allowed_mismatches, position, &equal_cells::<T>))
+	}
+
+
+
+	/* SCORED SUB-GRID FINDING METHODS */
+
+	/// Find all instances of the given sub-grid in self with their similarity. Returns all matches where the similarity reaches the given threshold, filtered and ordered by the given options. Returns nothing if the sub-grid cannot be searched for, use `try_find_all_scored` to get the reason.
+	pub fn find_all_scored(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, options:&FindOptions) -> Vec<SimilarityMatch> {
+		self.try_find_all_scored(sub_grid, similarity_threshold_factor, options).unwrap_or_default()
+	}
+
+	/// Find all instances of the given sub-grid in self with their similarity. Returns all matches where the similarity reaches the given threshold, filtered and ordered by the given options. Returns an error if the sub-grid is larger than self.
+	pub fn try_find_all_scored(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, options:&FindOptions) -> Result<Vec<SimilarityMatch>, GridError> {
+		self.try_find_all_scored_by(sub_grid, similarity_threshold_factor, options, &equal_cells::<T>)
+	}
+
+	/// Find all instances of the given sub-grid in self with their similarity, only matching the positive pixels of the given mask. Returns all matches where the similarity reaches the given threshold, filtered and ordered by the given options. Returns nothing if the sub-grid cannot be searched for, use `try_find_all_scored_masked` to get the reason.
+	pub fn find_all_scored_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, options:&FindOptions) -> Vec<SimilarityMatch> {
+		self.try_find_all_scored_masked(sub_grid, mask, similarity_threshold_factor, options).unwrap_or_default()
+	}
+
+	/// Find all instances of the given sub-grid in self with their similarity, only matching the positive pixels of the given mask. Returns all matches where the similarity reaches the given threshold, filtered and ordered by the given options. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_all_scored_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, options:&FindOptions) -> Result<Vec<SimilarityMatch>, GridError> {
+		self.try_find_all_scored_masked_by(sub_grid, mask, similarity_threshold_factor, options, &equal_cells::<T>)
+	}
+}
+
+
+
+impl<T> Grid<T> {
+
 	/* HELPER METHODS */
 
 	/// Validate that this grid is comparable to the other. Returns an error if something is wrong.
-	fn validate_comparable_grids(&self, other:&Grid<T>, mask:Option<&GridMask>) -> Result<(), Box<dyn Error>> {
+	fn validate_comparable_grids(&self, other:&Grid<T>, mask:Option<&GridMask>) -> Result<(), GridError> {
 		if (self.width, self.height) != (other.width, other.height) {
-			return Err(format!("Cannot get similarity between grids with differing width or height. Trying to compare grid {}x{} to grid {}x{}. Returning default value.", self.width, self.height, other.width, other.height).into());
+			return Err(GridError::SizeMismatch { expected: [self.width, self.height], found: [other.width, other.height] });
 		}
 		if self.data.len() != other.data.len() {
-			return Err(format!("Cannot get similarity between grids with differing length of data. Trying to compare {} length grid to {} length grid. Returning default value.", self.data.len(), other.data.len()).into());
+			return Err(GridError::DataLengthMismatch { expected: self.data.len(), found: other.data.len() });
 		}
 		if let Some(mask) = mask {
 			if (self.width, self.height) != (mask.width(), mask.height()) {
-				return Err(format!("Cannot get similarity between grids with a mask of differing width or height. Trying to compare grid {}x{} to mask {}x{}. Returning default value.", self.width, self.height, mask.width(), mask.height()).into());
+				return Err(GridError::SizeMismatch { expected: [self.width, self.height], found: [mask.width(), mask.height()] });
 			}
 		}
 		Ok(())
 	}
 
 	/// Validate that this grid can be used to find the other in. Returns an error if something is wrong.
-	fn validate_findable_grids(&self, other:&Grid<T>, mask:Option<&GridMask>) -> Result<(), Box<dyn Error>> {
+	fn validate_findable_grids(&self, other:&Grid<T>, mask:Option<&GridMask>) -> Result<(), GridError> {
 		if self.width < other.width || self.height < other.height {
-			return Err(format!("Cannot find sub-grid in this grid, the main grid should be larger than the sub-grid. Trying to find grid {}x{} in grid {}x{}", other.width, other.height, self.width, self.height).into());
+			return Err(GridError::SubGridTooLarge { grid: [self.width, self.height], sub_grid: [other.width, other.height] });
 		}
 		if let Some(mask) = mask {
 			if (other.width, other.height) != (mask.width(), mask.height()) {
-				return Err(format!("Cannot find sub-grid in this grid with a mask of differing width or height than the sub-grid. Trying to apply mask {}x{} to sub-grid {}x{}. Returning default value.", mask.width(), mask.height(), self.width, self.height).into());
+				return Err(GridError::SizeMismatch { expected: [other.width, other.height], found: [mask.width(), mask.height()] });
 			}
 		}
 		Ok(())
@@ -47,26 +191,28 @@ impl<T> Grid<T> where T:PartialEq {
 
 
 
-	/* SIMILARITY METHODS */
+	/* COMPARATOR SIMILARITY METHODS */
 
-	/// Compare this grid to another. Returns 'true' if the similarity reaches the given threshold.
-	pub fn similar_to(&self, other:&Grid<T>, similarity_threshold_factor:f32) -> bool {
+	/// Compare this grid to another, using the comparator to decide if two cells match. Returns 'true' if the similarity reaches the given threshold. Returns 'false' if the grids cannot be compared, use `try_similar_to_by` to get the reason.
+	pub fn similar_to_by<U>(&self, other:&Grid<T>, similarity_threshold_factor:f32, comparator:&U) -> bool where U:CellComparator<T> + ?Sized {
+		self.try_similar_to_by(other, similarity_threshold_factor, comparator).unwrap_or(false)
+	}
+
+	/// Compare this grid to another, using the comparator to decide if two cells match. Returns 'true' if the similarity reaches the given threshold. Returns an error if the grids are not the same size.
+	pub fn try_similar_to_by<U>(&self, other:&Grid<T>, similarity_threshold_factor:f32, comparator:&U) -> Result<bool, GridError> where U:CellComparator<T> + ?Sized {
 
 		// Validate grids same size.
-		if let Err(error) = self.validate_comparable_grids(other, None) {
-			eprintln!("{}", error);
-			return false;
-		}
+		self.validate_comparable_grids(other, None)?;
 
 		// Edge cases.
 		if similarity_threshold_factor <= 0.0 {
-			return true;
+			return Ok(true);
 		}
 		if similarity_threshold_factor == 1.0 {
-			return self.data == other.data;
+			return Ok(self.data.iter().zip(&other.data).all(|(left, right)| comparator.cells_match(left, right)));
 		}
 		if similarity_threshold_factor > 1.0 {
-			return false;
+			return Ok(false);
 		}
 
 		// Loop through pixels counting mismatches.
@@ -74,48 +220,56 @@ impl<T> Grid<T> where T:PartialEq {
 		let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
 		let mut mismatches:usize = 0;
 		for (left, right) in self.data.iter().zip(&other.data) {
-			if left != right {
+			if !comparator.cells_match(left, right) {
 				mismatches += 1;
 				if mismatches > max_mismatches {
-					return false;
+					return Ok(false);
 				}
 			}
 		}
-		return true;
+		Ok(true)
 	}
 
-	/// Compare this grid to another. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match.
-	pub fn similarity_to(&self, other:&Grid<T>) -> f32 {
+	/// Compare this grid to another, using the comparator to decide if two cells match. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. Returns 0.0 if the grids cannot be compared, use `try_similarity_to_by` to get the reason.
+	pub fn similarity_to_by<U>(&self, other:&Grid<T>, comparator:&U) -> f32 where U:CellComparator<T> + ?Sized {
+		self.try_similarity_to_by(other, comparator).unwrap_or(0.0)
+	}
+
+	/// Compare this grid to another, using the comparator to decide if two cells match. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. Returns an error if the grids are not the same size.
+	pub fn try_similarity_to_by<U>(&self, other:&Grid<T>, comparator:&U) -> Result<f32, GridError> where U:CellComparator<T> + ?Sized {
 
 		// Validate grids same size.
-		if let Err(error) = self.validate_comparable_grids(other, None) {
-			eprintln!("{}", error);
-			return 0.0;
-		}
+		self.validate_comparable_grids(other, None)?;
 
 		// Get similarity.
-		let matches:usize = self.data.iter().zip(&other.data).filter(|(left, right)| left == right).count();
-		matches as f32 / self.data.len() as f32
+		let matches:usize = self.data.iter().zip(&other.data).filter(|(left, right)| comparator.cells_match(left, right)).count();
+		Ok(matches as f32 / self.data.len() as f32)
 	}
 
-	/// Compare this grid to another. Only compare the pixels matching the given mask. Returns 'true' if the similarity reaches the given threshold.
-	pub fn similar_to_masked(&self, other:&Grid<T>, similarity_threshold_factor:f32, mask:&GridMask) -> bool {
+
+
+	/* MASKED COMPARATOR SIMILARITY METHODS */
+
+	/// Compare this grid to another, using the comparator to decide if two cells match. Only compare the pixels matching the given mask. Returns 'true' if the similarity reaches the given threshold. Returns 'false' if the grids cannot be compared, use `try_similar_to_masked_by` to get the reason.
+	pub fn similar_to_masked_by<U>(&self, other:&Grid<T>, similarity_threshold_factor:f32, mask:&GridMask, comparator:&U) -> bool where U:CellComparator<T> + ?Sized {
+		self.try_similar_to_masked_by(other, similarity_threshold_factor, mask, comparator).unwrap_or(false)
+	}
+
+	/// Compare this grid to another, using the comparator to decide if two cells match. Only compare the pixels matching the given mask. Returns 'true' if the similarity reaches the given threshold. Returns an error if the grids and mask are not the same size.
+	pub fn try_similar_to_masked_by<U>(&self, other:&Grid<T>, similarity_threshold_factor:f32, mask:&GridMask, comparator:&U) -> Result<bool, GridError> where U:CellComparator<T> + ?Sized {
 
 		// Validate grids same size.
-		if let Err(error) = self.validate_comparable_grids(other, Some(mask)) {
-			eprintln!("{}", error);
-			return false;
-		}
+		self.validate_comparable_grids(other, Some(mask))?;
 
 		// Edge cases.
 		if similarity_threshold_factor <= 0.0 {
-			return true;
+			return Ok(true);
 		}
 		if similarity_threshold_factor == 1.0 {
-			return mask.positive_ranges().iter().all(|range| range.clone().all(|index| self[index] == other[index]));
+			return Ok(mask.positive_ranges().iter().all(|range| range.clone().all(|index| comparator.cells_match(&self[index], &other[index]))));
 		}
 		if similarity_threshold_factor > 1.0 {
-			return false;
+			return Ok(false);
 		}
 
 		// Loop through pixels counting mismatches.
@@ -124,68 +278,75 @@ impl<T> Grid<T> where T:PartialEq {
 		let mut mismatches:usize = 0;
 		for index_range in mask.positive_ranges() {
 			for index in index_range.clone() {
-				if self[index] != other[index] {
+				if !comparator.cells_match(&self[index], &other[index]) {
 					mismatches += 1;
 					if mismatches > max_mismatches {
-						return false;
+						return Ok(false);
 					}
 				}
 			}
 		}
-		return true;
+		Ok(true)
 	}
 
+	/// Compare this grid to another, using the comparator to decide if two cells match. Only compare the pixels matching the given mask. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. Returns 0.0 if the grids cannot be compared, use `try_similarity_to_masked_by` to get the reason.
+	pub fn similarity_to_masked_by<U>(&self, other:&Grid<T>, mask:&GridMask, comparator:&U) -> f32 where U:CellComparator<T> + ?Sized {
+		self.try_similarity_to_masked_by(other, mask, comparator).unwrap_or(0.0)
+	}
 
-
-	/* MASKED SIMILARITY METHODS */
-
-	/// Compare this grid to another. Only compare the pixels matching the given mask. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match.
-	pub fn similarity_to_masked(&self, other:&Grid<T>, mask:&GridMask) -> f32 {
+	/// Compare this grid to another, using the comparator to decide if two cells match. Only compare the pixels matching the given mask. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. A mask without positive pixels gives a full match. Returns an error if the grids and mask are not the same size.
+	pub fn try_similarity_to_masked_by<U>(&self, other:&Grid<T>, mask:&GridMask, comparator:&U) -> Result<f32, GridError> where U:CellComparator<T> + ?Sized {
 
 		// Validate grids same size.
-		if let Err(error) = self.validate_comparable_grids(other, Some(mask)) {
-			eprintln!("{}", error);
-			return 0.0;
-		}
+		self.validate_comparable_grids(other, Some(mask))?;
 
 		// Get similarity.
 		let comparing_pixel_count:usize = mask.positive_ranges().iter().map(|range| range.end - range.start).sum();
-		let matches:usize = mask.positive_ranges().iter().map(|range| range.clone().filter(|&index| self[index] == other[index]).count()).sum();
-		matches as f32 / comparing_pixel_count as f32
+		if comparing_pixel_count == 0 {
+			return Ok(1.0);
+		}
+		let matches:usize = mask.positive_ranges().iter().map(|range| range.clone().filter(|&index| comparator.cells_match(&self[index], &other[index])).count()).sum();
+		Ok(matches as f32 / comparing_pixel_count as f32)
 	}
 
 
 
-	/* SUB-GRID FINDING METHODS */
+	/* COMPARATOR SUB-GRID FINDING METHODS */
 
-	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold.
-	pub fn find(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32) -> Option<[usize; 2]> {
+	/// Find the given sub-grid in self, using the comparator to decide if two cells match. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Returns None if the sub-grid cannot be searched for, use `try_find_by` to get the reason.
+	pub fn find_by<U>(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, comparator:&U) -> Option<[usize; 2]> where U:CellComparator<T> + ?Sized {
+		self.try_find_by(sub_grid, similarity_threshold_factor, comparator).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self, using the comparator to decide if two cells match. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Returns an error if the sub-grid is larger than self.
+	pub fn try_find_by<U>(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, comparator:&U) -> Result<Option<[usize; 2]>, GridError> where U:CellComparator<T> + ?Sized {
+		self.validate_findable_grids(sub_grid, None)?;
 		let comparing_pixel_count:usize = sub_grid.width * sub_grid.height;
 		let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
-		self.find_starting_at_position(sub_grid, max_mismatches, [0, 0])
+		Ok(self.scan_starting_at_position(sub_grid, max_mismatches, [0, 0], comparator))
 	}
 
-	/// Find all instances of the given sub-grid in self. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold.
-	pub fn find_all(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32) -> Vec<[usize; 2]> {
+	/// Find all instances of the given sub-grid in self, using the comparator to decide if two cells match. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold. Returns nothing if the sub-grid cannot be searched for, use `try_find_all_by` to get the reason.
+	pub fn find_all_by<U>(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, comparator:&U) -> Vec<[usize; 2]> where U:CellComparator<T> + ?Sized {
+		self.try_find_all_by(sub_grid, similarity_threshold_factor, comparator).unwrap_or_default()
+	}
+
+	/// Find all instances of the given sub-grid in self, using the comparator to decide if two cells match. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold. Returns an error if the sub-grid is larger than self.
+	pub fn try_find_all_by<U>(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, comparator:&U) -> Result<Vec<[usize; 2]>, GridError> where U:CellComparator<T> + ?Sized {
+		self.validate_findable_grids(sub_grid, None)?;
 		let comparing_pixel_count:usize = sub_grid.width * sub_grid.height;
 		let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
 		let mut cursor:[usize; 2] = [0, 0];
 		let mut results:Vec<[usize; 2]> = Vec::new();
-		while let Some(position) = self.find_starting_at_position(sub_grid, max_mismatches, cursor) {
+		while let Some(position) = self.scan_starting_at_position(sub_grid, max_mismatches, cursor, comparator) {
 			results.push(position);
 			cursor = [position[0] + 1, position[1]];
 		}
-		results
+		Ok(results)
 	}
-	
-	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold. Starts at the given position.
-	pub fn find_starting_at_position(&self, sub_grid:&Grid<T>, max_allowed_mismatches:usize, position:[usize; 2]) -> Option<[usize; 2]> {
 
-		// Validate grids same size.
-		if let Err(error) = self.validate_findable_grids(sub_grid, None) {
-			eprintln!("{}", error);
-			return None;
-		}
+	/// Scan for the given sub-grid in self, starting at the given position. Assumes the sub-grid fits in self.
+	fn scan_starting_at_position<U>(&self, sub_grid:&Grid<T>, max_allowed_mismatches:usize, position:[usize; 2], comparator:&U) -> Option<[usize; 2]> where U:CellComparator<T> + ?Sized {
 
 		// Loop through all possible top-left positions.
 		let self_row_shift:usize = self.width - sub_grid.width;
@@ -199,7 +360,7 @@ impl<T> Grid<T> where T:PartialEq {
 		}
 		while origin_y < end_y {
 			while origin_x < end_x {
-				if self.find_at_position(sub_grid, max_allowed_mismatches, [origin_x, origin_y], self_row_shift) {
+				if self.find_at_position(sub_grid, max_allowed_mismatches, [origin_x, origin_y], self_row_shift, comparator) {
 					return Some([origin_x, origin_y]);
 				}
 				origin_x += 1;
@@ -213,58 +374,48 @@ impl<T> Grid<T> where T:PartialEq {
 	}
 
 	/// Check if a sub-grid is at a specific position in self.
-	fn find_at_position(&self, sub_grid:&Grid<T>, max_allowed_mismatches:usize, position:[usize; 2], self_row_shift:usize) -> bool {
-		let mut mismatches:usize = 0;
-		let mut self_index:usize = position[1] * self.width + position[0];
-		let mut sub_index:usize = 0;
-		for _sub_y in 0..sub_grid.height {
-			for _sub_x in 0..sub_grid.width {
-				if self[self_index] != sub_grid[sub_index] {
-					mismatches += 1;
-					if mismatches > max_allowed_mismatches {
-						return false;
-					}
-				}
-				self_index += 1;
-				sub_index += 1;
-			}
-			self_index += self_row_shift;
-		}
-		return true;
+	fn find_at_position<U>(&self, sub_grid:&Grid<T>, max_allowed_mismatches:usize, position:[usize; 2], self_row_shift:usize, comparator:&U) -> bool where U:CellComparator<T> + ?Sized {
+		self.mismatches_at_position(sub_grid, max_allowed_mismatches, position, self_row_shift, comparator).is_some()
 	}
 
 
 
-	/* MASKED SUB-GRID FINDING METHODS */
+	/* MASKED COMPARATOR SUB-GRID FINDING METHODS */
 
-	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold, only matching the positive pixels of the given mask.
-	pub fn find_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32) -> Option<[usize; 2]> {
+	/// Find the given sub-grid in self, using the comparator to decide if two cells match. Returns the topleft coordinates of the first position where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Returns None if the sub-grid cannot be searched for, use `try_find_masked_by` to get the reason.
+	pub fn find_masked_by<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, comparator:&U) -> Option<[usize; 2]> where U:CellComparator<T> + ?Sized {
+		self.try_find_masked_by(sub_grid, mask, similarity_threshold_factor, comparator).unwrap_or_default()
+	}
+
+	/// Find the given sub-grid in self, using the comparator to decide if two cells match. Returns the topleft coordinates of the first position where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_masked_by<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, comparator:&U) -> Result<Option<[usize; 2]>, GridError> where U:CellComparator<T> + ?Sized {
+		self.validate_findable_grids(sub_grid, Some(mask))?;
 		let comparing_pixel_count:usize = mask.positive_ranges().iter().map(|range| range.end - range.start).sum();
 		let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
-		self.find_starting_at_position_masked(sub_grid, mask, max_mismatches, [0, 0])
+		Ok(self.scan_starting_at_position_masked(sub_grid, mask, max_mismatches, [0, 0], comparator))
 	}
 
-	/// Find all instances of the given sub-grid in self. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold, only matching the positive pixels of the given mask.
-	pub fn find_all_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32) -> Vec<[usize; 2]> {
+	/// Find all instances of the given sub-grid in self, using the comparator to decide if two cells match. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Returns nothing if the sub-grid cannot be searched for, use `try_find_all_masked_by` to get the reason.
+	pub fn find_all_masked_by<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, comparator:&U) -> Vec<[usize; 2]> where U:CellComparator<T> + ?Sized {
+		self.try_find_all_masked_by(sub_grid, mask, similarity_threshold_factor, comparator).unwrap_or_default()
+	}
+
+	/// Find all instances of the given sub-grid in self, using the comparator to decide if two cells match. Returns the topleft coordinates of the all positions where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_all_masked_by<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, comparator:&U) -> Result<Vec<[usize; 2]>, GridError> where U:CellComparator<T> + ?Sized {
+		self.validate_findable_grids(sub_grid, Some(mask))?;
 		let comparing_pixel_count:usize = mask.positive_ranges().iter().map(|range| range.end - range.start).sum();
 		let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
 		let mut cursor:[usize; 2] = [0, 0];
 		let mut results:Vec<[usize; 2]> = Vec::new();
-		while let Some(position) = self.find_starting_at_position_masked(sub_grid, mask, max_mismatches, cursor) {
+		while let Some(position) = self.scan_starting_at_position_masked(sub_grid, mask, max_mismatches, cursor, comparator) {
 			results.push(position);
 			cursor = [position[0] + 1, position[1]];
 		}
-		results
+		Ok(results)
 	}
-	
-	/// Find the given sub-grid in self. Returns the topleft coordinates of the first position where the similarity reaches the given threshold, only matching the positive pixels of the given mask. Starts at the given position.
-	pub fn find_starting_at_position_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, max_allowed_mismatches:usize, position:[usize; 2]) -> Option<[usize; 2]> {
 
-		// Validate grids same size.
-		if let Err(error) = self.validate_findable_grids(sub_grid, None) {
-			eprintln!("{}", error);
-			return None;
-		}
+	/// Scan for the given sub-grid in self, only matching the positive pixels of the given mask, starting at the given position. Assumes the sub-grid fits in self.
+	fn scan_starting_at_position_masked<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, max_allowed_mismatches:usize, position:[usize; 2], comparator:&U) -> Option<[usize; 2]> where U:CellComparator<T> + ?Sized {
 
 		// Initialize variables used in the loop.
 		let self_row_shift:usize = self.width - sub_grid.width;
@@ -280,7 +431,7 @@ impl<T> Grid<T> where T:PartialEq {
 		// Loop through all possible top-left positions.
 		while origin_y < end_y {
 			while origin_x < end_x {
-				if self.find_at_position_masked(sub_grid, mask, max_allowed_mismatches, [origin_x, origin_y], self_row_shift) {
+				if self.find_at_position_masked(sub_grid, mask, max_allowed_mismatches, [origin_x, origin_y], self_row_shift, comparator) {
 					return Some([origin_x, origin_y]);
 				}
 				origin_x += 1;
@@ -294,21 +445,112 @@ impl<T> Grid<T> where T:PartialEq {
 	}
 
 	/// Check if a sub-grid is at a specific position in self. Only count pixels matching the mask.
-	fn find_at_position_masked(&self, sub_grid:&Grid<T>, mask:&GridMask, max_allowed_mismatches:usize, position:[usize; 2], self_row_shift:usize) -> bool {
+	fn find_at_position_masked<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, max_allowed_mismatches:usize, position:[usize; 2], self_row_shift:usize, comparator:&U) -> bool where U:CellComparator<T> + ?Sized {
+		self.mismatches_at_position_masked(sub_grid, mask, max_allowed_mismatches, position, self_row_shift, comparator).is_some()
+	}
+
+
+
+	/* SCORED COMPARATOR SUB-GRID FINDING METHODS */
+
+	/// Find all instances of the given sub-grid in self with their similarity, using the comparator to decide if two cells match. Returns all matches where the similarity reaches the given threshold, filtered and ordered by the given options. Returns nothing if the sub-grid cannot be searched for, use `try_find_all_scored_by` to get the reason.
+	pub fn find_all_scored_by<U>(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, options:&FindOptions, comparator:&U) -> Vec<SimilarityMatch> where U:CellComparator<T> + ?Sized {
+		self.try_find_all_scored_by(sub_grid, similarity_threshold_factor, options, comparator).unwrap_or_default()
+	}
+
+	/// Find all instances of the given sub-grid in self with their similarity, using the comparator to decide if two cells match. Returns all matches where the similarity reaches the given threshold, filtered and ordered by the given options. Returns an error if the sub-grid is larger than self.
+	pub fn try_find_all_scored_by<U>(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32, options:&FindOptions, comparator:&U) -> Result<Vec<SimilarityMatch>, GridError> where U:CellComparator<T> + ?Sized {
+		self.validate_findable_grids(sub_grid, None)?;
+
+		// Score all positions that reach the threshold.
+		let comparing_pixel_count:usize = sub_grid.width * sub_grid.height;
+		let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
+		let self_row_shift:usize = self.width - sub_grid.width;
+		let mut matches:Vec<SimilarityMatch> = Vec::new();
+		for origin_y in 0..self.height - sub_grid.height + 1 {
+			for origin_x in 0..self.width - sub_grid.width + 1 {
+				if let Some(mismatches) = self.mismatches_at_position(sub_grid, max_mismatches, [origin_x, origin_y], self_row_shift, comparator) {
+					let similarity:f32 = if comparing_pixel_count == 0 { 1.0 } else { 1.0 - mismatches as f32 / comparing_pixel_count as f32 };
+					matches.push(SimilarityMatch { position: [origin_x, origin_y], similarity });
+				}
+			}
+		}
+
+		// Apply options.
+		Ok(options.apply(matches, [sub_grid.width, sub_grid.height]))
+	}
+
+	/// Find all instances of the given sub-grid in self with their similarity, using the comparator to decide if two cells match, only matching the positive pixels of the given mask. Returns all matches where the similarity reaches the given threshold, filtered and ordered by the given options. Returns nothing if the sub-grid cannot be searched for, use `try_find_all_scored_masked_by` to get the reason.
+	pub fn find_all_scored_masked_by<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, options:&FindOptions, comparator:&U) -> Vec<SimilarityMatch> where U:CellComparator<T> + ?Sized {
+		self.try_find_all_scored_masked_by(sub_grid, mask, similarity_threshold_factor, options, comparator).unwrap_or_default()
+	}
+
+	/// Find all instances of the given sub-grid in self with their similarity, using the comparator to decide if two cells match, only matching the positive pixels of the given mask. Returns all matches where the similarity reaches the given threshold, filtered and ordered by the given options. Returns an error if the sub-grid is larger than self or the mask is not the size of the sub-grid.
+	pub fn try_find_all_scored_masked_by<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, similarity_threshold_factor:f32, options:&FindOptions, comparator:&U) -> Result<Vec<SimilarityMatch>, GridError> where U:CellComparator<T> + ?Sized {
+		self.validate_findable_grids(sub_grid, Some(mask))?;
+
+		// Score all positions that reach the threshold.
+		let comparing_pixel_count:usize = mask.positive_ranges().iter().map(|range| range.end - range.start).sum();
+		let max_mismatches:usize = ((1.0 - similarity_threshold_factor) * comparing_pixel_count as f32).round() as usize;
+		let self_row_shift:usize = self.width - sub_grid.width;
+		let mut matches:Vec<SimilarityMatch> = Vec::new();
+		for origin_y in 0..self.height - sub_grid.height + 1 {
+			for origin_x in 0..self.width - sub_grid.width + 1 {
+				if let Some(mismatches) = self.mismatches_at_position_masked(sub_grid, mask, max_mismatches, [origin_x, origin_y], self_row_shift, comparator) {
+					let similarity:f32 = if comparing_pixel_count == 0 { 1.0 } else { 1.0 - mismatches as f32 / comparing_pixel_count as f32 };
+					matches.push(SimilarityMatch { position: [origin_x, origin_y], similarity });
+				}
+			}
+		}
+
+		// Apply options.
+		Ok(options.apply(matches, [sub_grid.width, sub_grid.height]))
+	}
+
+	/// Count the mismatches of a sub-grid at a specific position in self. Returns None if there are more mismatches than allowed.
+	pub(crate) fn mismatches_at_position<U>(&self, sub_grid:&Grid<T>, max_allowed_mismatches:usize, position:[usize; 2], self_row_shift:usize, comparator:&U) -> Option<usize> where U:CellComparator<T> + ?Sized {
+		let mut mismatches:usize = 0;
+		let mut self_index:usize = position[1] * self.width + position[0];
+		let mut sub_index:usize = 0;
+		for _sub_y in 0..sub_grid.height {
+			for _sub_x in 0..sub_grid.width {
+				if !comparator.cells_match(&self[self_index], &sub_grid[sub_index]) {
+					mismatches += 1;
+					if mismatches > max_allowed_mismatches {
+						return None;
+					}
+				}
+				self_index += 1;
+				sub_index += 1;
+			}
+			self_index += self_row_shift;
+		}
+		Some(mismatches)
+	}
+
+	/// Count the mismatches of a sub-grid at a specific position in self. Only count pixels matching the mask. Returns None if there are more mismatches than allowed.
+	pub(crate) fn mismatches_at_position_masked<U>(&self, sub_grid:&Grid<T>, mask:&GridMask, max_allowed_mismatches:usize, position:[usize; 2], self_row_shift:usize, comparator:&U) -> Option<usize> where U:CellComparator<T> + ?Sized {
 		let mut mismatches:usize = 0;
 		let self_to_sub_start_offset:usize = position[1] * self.width + position[0];
 		for range in mask.positive_ranges() {
 			for sub_index in range.clone() {
 				let sub_y:usize = sub_index / sub_grid.width;
 				let self_index:usize = self_to_sub_start_offset + sub_y * self_row_shift + sub_index;
-				if self[self_index] != sub_grid[sub_index] {
+				if !comparator.cells_match(&self[self_index], &sub_grid[sub_index]) {
 					mismatches += 1;
 					if mismatches > max_allowed_mismatches {
-						return false;
+						return None;
 					}
 				}
 			}
 		}
-		return true;
+		Some(mismatches)
 	}
+}
+
+
+
+/// Compare two cells using their PartialEq implementation.
+fn equal_cells<T>(left:&T, right:&T) -> bool where T:PartialEq {
+	left == right
 }
\ No newline at end of file
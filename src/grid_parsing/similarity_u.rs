@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-	use crate::{ Grid, GridMask};
+	use crate::{ Color, ColorTolerance, DistanceComparator, FindOptions, Grid, GridError, GridMask, SimilarityMatch };
 
 
 
@@ -102,4 +102,96 @@ mod test {
 
 		assert_eq!(grid.find_masked(&sub_grid, &mask, 1.0), Some([(FILL_TARGET[0] - 1) * TILE_SIZE, FILL_TARGET[1] * TILE_SIZE]));
 	}
+
+	#[test]
+	fn test_try_similarity_errors() {
+		let grid:Grid<i32> = Grid::new(vec![0, 1, 2, 3, 4, 5, 6, 7, 8], 3, 3);
+		let other:Grid<i32> = Grid::new(vec![0, 1, 2, 3, 4, 5], 3, 2);
+		let mask:GridMask = GridMask::new(Grid::new(vec![true; 4], 2, 2));
+
+		assert!(matches!(grid.try_similarity_to(&other), Err(GridError::SizeMismatch { expected: [3, 3], found: [3, 2] })));
+		assert!(matches!(grid.try_similar_to_masked(&grid, 0.5, &mask), Err(GridError::SizeMismatch { .. })));
+		assert!(matches!(other.try_find(&grid, 1.0), Err(GridError::SubGridTooLarge { .. })));
+		assert!(matches!(grid.try_find_all_masked(&other, &mask, 1.0), Err(GridError::SizeMismatch { .. })));
+		assert_eq!(grid.try_similarity_to(&grid).unwrap(), 1.0);
+		assert_eq!(grid.try_find_all(&Grid::new(vec![4], 1, 1), 1.0).unwrap(), vec![[1, 1]]);
+	}
+
+	#[test]
+	fn test_similarity_by() {
+		let grid:Grid<i32> = Grid::new(vec![0, 10, 20, 30, 40, 50, 60, 70, 80], 3, 3);
+		let noisy_grid:Grid<i32> = Grid::new(vec![1, 9, 20, 32, 40, 49, 61, 70, 90], 3, 3);
+		println!("[grid]\n{grid}\n");
+		println!("[noisy grid]\n{noisy_grid}\n");
+
+		let comparator = |left:&i32, right:&i32| (left - right).abs() <= 2;
+		assert_eq!(grid.similarity_to(&noisy_grid), 3.0 / 9.0);
+		assert_eq!(grid.similarity_to_by(&noisy_grid, &comparator), 8.0 / 9.0);
+		assert!(grid.similar_to_by(&noisy_grid, 8.0 / 9.0, &comparator));
+		assert!(!grid.similar_to_by(&noisy_grid, 1.0, &comparator));
+
+		let distance_comparator = DistanceComparator::new(|left:&i32, right:&i32| (left - right).abs() as f64, 10.0);
+		assert!(grid.similar_to_by(&noisy_grid, 1.0, &distance_comparator));
+
+		let mask:GridMask = GridMask::new(Grid::new(vec![true, true, true, true, true, true, true, true, false], 3, 3));
+		assert_eq!(grid.similarity_to_masked_by(&noisy_grid, &mask, &comparator), 1.0);
+		assert!(grid.similar_to_masked_by(&noisy_grid, 1.0, &mask, &comparator));
+		assert_eq!(grid.similarity_to_masked(&noisy_grid, &GridMask::new(Grid::new(vec![false; 9], 3, 3))), 1.0);
+	}
+
+	#[test]
+	fn test_find_by() {
+		let mut grid:Grid<Color> = Grid::new(vec![Color(0xFF000000); 36], 6, 6);
+		let sub_grid:Grid<Color> = Grid::new(vec![Color(0xFF808080), Color(0xFFFFFFFF), Color(0xFFFFFFFF), Color(0xFF808080)], 2, 2);
+		for (offset, color) in [(0, 0xFF828080), (1, 0xFFFDFFFF), (6, 0x80FFFEFF), (7, 0xFF7F8081)] {
+			grid[14 + offset] = Color(color);
+		}
+		grid[3] = Color(0xFF808080);
+		grid[4] = Color(0xFFFFFFFF);
+		grid[9] = Color(0xFFFFFFFF);
+		grid[10] = Color(0xFF808080);
+		println!("[grid]\n{grid}\n");
+		println!("[sub grid]\n{sub_grid}\n");
+
+		let tolerance:ColorTolerance = ColorTolerance::new(3).ignoring_alpha();
+		assert_eq!(grid.find(&sub_grid, 1.0), Some([3, 0]));
+		assert_eq!(grid.find_all(&sub_grid, 1.0), vec![[3, 0]]);
+		assert_eq!(grid.find_by(&sub_grid, 1.0, &tolerance), Some([3, 0]));
+		assert_eq!(grid.find_all_by(&sub_grid, 1.0, &tolerance), vec![[3, 0], [2, 2]]);
+		assert_eq!(grid.find_all_by(&sub_grid, 1.0, &ColorTolerance::new(3)), vec![[3, 0]]);
+
+		let mask:GridMask = GridMask::new(Grid::new(vec![true, true, false, true], 2, 2));
+		assert_eq!(grid.find_all_masked_by(&sub_grid, &mask, 1.0, &ColorTolerance::new(3)), vec![[3, 0], [2, 2]]);
+		assert!(matches!(sub_grid.try_find_by(&grid, 1.0, &tolerance), Err(GridError::SubGridTooLarge { .. })));
+	}
+
+	#[test]
+	fn test_find_all_scored() {
+		let mut grid:Grid<char> = Grid::new(vec![' '; 8 * 5], 8, 5);
+		for index in [9, 10, 17, 18, 13, 14, 21] {
+			grid[index] = 'x';
+		}
+		let sub_grid:Grid<char> = Grid::new(vec!['x'; 4], 2, 2);
+		println!("[grid]\n{grid}\n");
+		println!("[sub grid]\n{sub_grid}\n");
+
+		assert_eq!(grid.find_all(&sub_grid, 0.5).len(), grid.find_all_scored(&sub_grid, 0.5, &FindOptions::new()).len());
+		assert_eq!(grid.find_all_scored(&sub_grid, 0.5, &FindOptions::new().non_overlapping()), vec![
+			SimilarityMatch { position: [1, 1], similarity: 1.0 },
+			SimilarityMatch { position: [5, 1], similarity: 0.75 }
+		]);
+		assert_eq!(grid.find_all_scored(&sub_grid, 0.5, &FindOptions::new().non_overlapping().with_max_results(1)), vec![SimilarityMatch { position: [1, 1], similarity: 1.0 }]);
+		assert_eq!(grid.find_all_scored(&sub_grid, 0.75, &FindOptions::new().with_min_distance(3.0).sorted_by_score()).iter().map(|found| found.position).collect::<Vec<[usize; 2]>>(), vec![[1, 1], [5, 1]]);
+
+		let mask:GridMask = GridMask::new(Grid::new(vec![true, true, true, false], 2, 2));
+		assert_eq!(grid.find_all_scored_masked(&sub_grid, &mask, 1.0, &FindOptions::new().non_overlapping()), vec![
+			SimilarityMatch { position: [1, 1], similarity: 1.0 },
+			SimilarityMatch { position: [5, 1], similarity: 1.0 }
+		]);
+		assert!(matches!(sub_grid.try_find_all_scored(&grid, 1.0, &FindOptions::new()), Err(GridError::SubGridTooLarge { .. })));
+
+		let empty_matches:Vec<SimilarityMatch> = grid.find_all_scored(&Grid::new(Vec::new(), 0, 0), 1.0, &FindOptions::new());
+		assert_eq!(empty_matches.len(), 9 * 6);
+		assert!(empty_matches.iter().all(|found| found.similarity == 1.0));
+	}
 }
\ No newline at end of file
@@ -0,0 +1,353 @@
+use crate::{ Color, Grid, GridError, GridMask, GridNumeric, IntegralGrid };
+
+
+
+const FLAT_VARIANCE_EPSILON:f64 = 1e-9;
+const FLAT_MEAN_TOLERANCE:f64 = 1e-6;
+const FFT_MIN_TEMPLATE_AREA:usize = 64; // Smaller templates are faster to correlate directly.
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TemplateMatchMethod {
+	SquaredDifference, // Sum of squared differences, lower is better.
+	AbsoluteDifference, // Sum of absolute differences, lower is better.
+	NormalizedCrossCorrelation // Zero-mean normalized cross-correlation between -1.0 and 1.0, higher is better.
+}
+impl TemplateMatchMethod {
+
+	/// Whether or not a higher score means a better match.
+	pub fn higher_is_better(&self) -> bool {
+		matches!(self, TemplateMatchMethod::NormalizedCrossCorrelation)
+	}
+
+	/// Whether or not the first score is a better match than the second.
+	pub fn is_better(&self, score:f32, other:f32) -> bool {
+		if self.higher_is_better() { score > other } else { score < other }
+	}
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TemplateMatch {
+	pub position:[usize; 2],
+	pub score:f32
+}
+impl TemplateMatch {
+
+	/// Find the best match in a score map. Returns the first position in case of equal scores. Returns None if the score map is empty.
+	pub fn best_in(score_map:&Grid<f32>, method:TemplateMatchMethod) -> Option<TemplateMatch> {
+		let mut best:Option<(usize, f32)> = None;
+		for (index, score) in score_map.data.iter().enumerate() {
+			match best {
+				Some((_, best_score)) if !method.is_better(*score, best_score) => {},
+				_ => best = Some((index, *score))
+			}
+		}
+		best.map(|(index, score)| TemplateMatch { position: score_map.index_to_xy(index), score })
+	}
+}
+
+
+
+pub trait TemplateMatchable:Sized {
+
+	/// Split a grid into planes of floats that are matched separately and combined into a single score.
+	fn match_planes(grid:&Grid<Self>) -> Vec<Grid<f64>>;
+}
+impl<T> TemplateMatchable for T where T:GridNumeric {
+	fn match_planes(grid:&Grid<Self>) -> Vec<Grid<f64>> {
+		vec![grid.map_ref(|value| value.to_f64())]
+	}
+}
+impl TemplateMatchable for Color {
+	fn match_planes(grid:&Grid<Self>) -> Vec<Grid<f64>> {
+		vec![
+			grid.map_ref(|color| *color.r() as f64),
+			grid.map_ref(|color| *color.g() as f64),
+			grid.map_ref(|color| *color.b() as f64)
+		]
+	}
+}
+
+
+
+impl<T> Grid<T> where T:TemplateMatchable {
+
+	/* SCORE MAP METHODS */
+
+	/// Score every position the template fits in self using the given method. The score map has a value for each possible top-left position of the template. Returns an error if the template is larger than self. Squared difference and cross-correlation scores of templates with at least 64 pixels are computed through an FFT, taking O(W·H·log(W·H)) time. Smaller templates and absolute difference scores compare every template pixel at every position, taking O(W·H·w·h) time.
+	pub fn match_template(&self, template:&Grid<T>, method:TemplateMatchMethod) -> Result<Grid<f32>, GridError> {
+		self.template_score_map(template, None, method)
+	}
+
+	/// Score every position the template fits in self using the given method, only comparing the positive pixels of the mask. Returns an error if the template is larger than self or the mask is not the size of the template. Compares every positive mask pixel at every position, taking O(W·H·n) time for n positive mask pixels.
+	pub fn match_template_masked(&self, template:&Grid<T>, mask:&GridMask, method:TemplateMatchMethod) -> Result<Grid<f32>, GridError> {
+		self.template_score_map(template, Some(mask), method)
+	}
+
+	/// Find the position where the template matches best using the given method. Returns an error if the template is larger than self.
+	pub fn find_template(&self, template:&Grid<T>, method:TemplateMatchMethod) -> Result<TemplateMatch, GridError> {
+		let score_map:Grid<f32> = self.match_template(template, method)?;
+		Ok(TemplateMatch::best_in(&score_map, method).expect("Score map of a fitting template is never empty."))
+	}
+
+	/// Find the position where the template matches best using the given method, only comparing the positive pixels of the mask. Returns an error if the template is larger than self or the mask is not the size of the template.
+	pub fn find_template_masked(&self, template:&Grid<T>, mask:&GridMask, method:TemplateMatchMethod) -> Result<TemplateMatch, GridError> {
+		let score_map:Grid<f32> = self.match_template_masked(template, mask, method)?;
+		Ok(TemplateMatch::best_in(&score_map, method).expect("Score map of a fitting template is never empty."))
+	}
+
+
+
+	/* SCORING METHODS */
+
+	/// Create the score map of the template, optionally only comparing the positive pixels of the mask.
+	fn template_score_map(&self, template:&Grid<T>, mask:Option<&GridMask>, method:TemplateMatchMethod) -> Result<Grid<f32>, GridError> {
+
+		// Validate sizes.
+		if self.width < template.width || self.height < template.height {
+			return Err(GridError::SubGridTooLarge { grid: [self.width, self.height], sub_grid: [template.width, template.height] });
+		}
+		if let Some(mask) = mask && [mask.width(), mask.height()] != [template.width, template.height] {
+			return Err(GridError::SizeMismatch { expected: [template.width, template.height], found: [mask.width(), mask.height()] });
+		}
+
+		// Prepare planes and compared offsets. Each offset is the index in the template and the index relative to the top-left position in self.
+		let source_planes:Vec<Grid<f64>> = T::match_planes(self);
+		let template_planes:Vec<Grid<f64>> = T::match_planes(template);
+		let offsets:Vec<[usize; 2]> = match mask {
+			Some(mask) => mask.positive_ranges().iter().flat_map(|range| range.clone()).collect::<Vec<usize>>(),
+			None => (0..template.width * template.height).collect()
+		}.into_iter().map(|index| [index, (index / template.width) * self.width + index % template.width]).collect();
+		let matcher:PlaneMatcher = PlaneMatcher { source_planes, template_planes, offsets, template_size: [template.width, template.height], masked: mask.is_some() };
+
+		// Score each position.
+		let map_width:usize = self.width - template.width + 1;
+		let map_height:usize = self.height - template.height + 1;
+		let scores:Vec<f32> = match method {
+			TemplateMatchMethod::SquaredDifference => matcher.squared_difference_scores(map_width, map_height),
+			TemplateMatchMethod::AbsoluteDifference => matcher.absolute_difference_scores(map_width, map_height),
+			TemplateMatchMethod::NormalizedCrossCorrelation => matcher.normalized_cross_correlation_scores(map_width, map_height)
+		};
+		Ok(Grid::new(scores, map_width, map_height))
+	}
+}
+
+
+
+struct PlaneMatcher {
+	source_planes:Vec<Grid<f64>>,
+	template_planes:Vec<Grid<f64>>,
+	offsets:Vec<[usize; 2]>,
+	template_size:[usize; 2],
+	masked:bool
+}
+impl PlaneMatcher {
+
+	/// Get the index of the top-left position in the source planes.
+	fn origin(&self, x:usize, y:usize) -> usize {
+		y * self.source_planes[0].width + x
+	}
+
+	/// Get the sum of the products of source and template values at every position, using the given template planes. Large unmasked templates are correlated through an FFT.
+	fn cross_sums(&self, template_planes:&[Grid<f64>], map_width:usize, map_height:usize) -> Vec<f64> {
+		if !self.masked && self.offsets.len() >= FFT_MIN_TEMPLATE_AREA {
+			let mut cross_sums:Vec<f64> = vec![0.0; map_width * map_height];
+			for (source, template) in self.source_planes.iter().zip(template_planes) {
+				for (cross_sum, plane_cross_sum) in cross_sums.iter_mut().zip(fft_cross_correlation(source, template)) {
+					*cross_sum += plane_cross_sum;
+				}
+			}
+			return cross_sums;
+		}
+		(0..map_width * map_height).map(|index| {
+			let origin:usize = self.origin(index % map_width, index / map_width);
+			self.source_planes.iter().zip(template_planes).map(|(source, template)| {
+				self.offsets.iter().map(|[template_index, source_offset]| source.data[origin + source_offset] * template.data[*template_index]).sum::<f64>()
+			}).sum()
+		}).collect()
+	}
+
+	/// Score all positions by the sum of squared differences. Unmasked searches get the squared sums of the source from integral grids.
+	fn squared_difference_scores(&self, map_width:usize, map_height:usize) -> Vec<f32> {
+		let mut scores:Vec<f32> = Vec::with_capacity(map_width * map_height);
+		if self.masked {
+			for y in 0..map_height {
+				for x in 0..map_width {
+					let origin:usize = self.origin(x, y);
+					scores.push(self.source_planes.iter().zip(&self.template_planes).map(|(source, template)| {
+						self.offsets.iter().map(|[template_index, source_offset]| (source.data[origin + source_offset] - template.data[*template_index]).powi(2)).sum::<f64>()
+					}).sum::<f64>() as f32);
+				}
+			}
+		} else {
+			let integrals:Vec<IntegralGrid> = self.source_planes.iter().map(|plane| plane.integral_with_squared_sums()).collect();
+			let template_squared_sum:f64 = self.template_planes.iter().flat_map(|plane| plane.data.iter()).map(|value| value * value).sum();
+			let cross_sums:Vec<f64> = self.cross_sums(&self.template_planes, map_width, map_height);
+			for y in 0..map_height {
+				for x in 0..map_width {
+					let bounds:[usize; 4] = [x, y, self.template_size[0], self.template_size[1]];
+					let source_squared_sum:f64 = integrals.iter().map(|integral| integral.squared_sum(bounds).unwrap_or_default()).sum();
+					let score:f64 = source_squared_sum - 2.0 * cross_sums[y * map_width + x] + template_squared_sum;
+					scores.push(score.max(0.0) as f32);
+				}
+			}
+		}
+		scores
+	}
+
+	/// Score all positions by the sum of absolute differences.
+	fn absolute_difference_scores(&self, map_width:usize, map_height:usize) -> Vec<f32> {
+		let mut scores:Vec<f32> = Vec::with_capacity(map_width * map_height);
+		for y in 0..map_height {
+			for x in 0..map_width {
+				let origin:usize = self.origin(x, y);
+				scores.push(self.source_planes.iter().zip(&self.template_planes).map(|(source, template)| {
+					self.offsets.iter().map(|[template_index, source_offset]| (source.data[origin + source_offset] - template.data[*template_index]).abs()).sum::<f64>()
+				}).sum::<f64>() as f32);
+			}
+		}
+		scores
+	}
+
+	/// Score all positions by the zero-mean normalized cross-correlation. Unmasked searches get the sums of the source from integral grids.
+	fn normalized_cross_correlation_scores(&self, map_width:usize, map_height:usize) -> Vec<f32> {
+		let count:f64 = self.offsets.len() as f64;
+
+		// Subtract the mean of the compared pixels from each template plane.
+		let template_means:Vec<f64> = self.template_planes.iter().map(|plane| {
+			if count == 0.0 { 0.0 } else { self.offsets.iter().map(|[template_index, _]| plane.data[*template_index]).sum::<f64>() / count }
+		}).collect();
+		let centered_templates:Vec<Grid<f64>> = self.template_planes.iter().zip(&template_means).map(|(plane, mean)| plane.map_ref(|value| value - mean)).collect();
+		let template_variance:f64 = centered_templates.iter().map(|plane| self.offsets.iter().map(|[template_index, _]| plane.data[*template_index].powi(2)).sum::<f64>()).sum();
+		let cross_sums:Vec<f64> = self.cross_sums(&centered_templates, map_width, map_height);
+
+		// Score each position.
+		let integrals:Vec<IntegralGrid> = if self.masked { Vec::new() } else { self.source_planes.iter().map(|plane| plane.integral_with_squared_sums()).collect() };
+		let mut scores:Vec<f32> = Vec::with_capacity(map_width * map_height);
+		for y in 0..map_height {
+			for x in 0..map_width {
+				let origin:usize = self.origin(x, y);
+				let bounds:[usize; 4] = [x, y, self.template_size[0], self.template_size[1]];
+				let mut source_variance:f64 = 0.0;
+				let mut means_match:bool = true;
+				for (plane_index, plane) in self.source_planes.iter().enumerate() {
+					let (sum, squared_sum) = if self.masked {
+						self.offsets.iter().map(|[_, source_offset]| plane.data[origin + source_offset]).fold((0.0, 0.0), |(sum, squared_sum), value| (sum + value, squared_sum + value * value))
+					} else {
+						(integrals[plane_index].sum(bounds), integrals[plane_index].squared_sum(bounds).unwrap_or_default())
+					};
+					source_variance += squared_sum - sum * sum / count;
+					means_match &= (sum / count - template_means[plane_index]).abs() <= FLAT_MEAN_TOLERANCE;
+				}
+				scores.push(Self::correlation_score(cross_sums[y * map_width + x], template_variance, source_variance, means_match));
+			}
+		}
+		scores
+	}
+
+	/// Combine the cross sum and variances into a correlation score. Two flat areas are considered a full match if their means match and unrelated otherwise, a flat area and a varying area are considered unrelated.
+	fn correlation_score(cross_sum:f64, template_variance:f64, source_variance:f64, means_match:bool) -> f32 {
+		let template_flat:bool = template_variance <= FLAT_VARIANCE_EPSILON;
+		let source_flat:bool = source_variance <= FLAT_VARIANCE_EPSILON;
+		match (template_flat, source_flat) {
+			(true, true) if means_match => 1.0,
+			(false, false) => (cross_sum / (template_variance * source_variance).sqrt()).clamp(-1.0, 1.0) as f32,
+			_ => 0.0
+		}
+	}
+}
+
+
+
+/// Get the sum of the products of source and template values at every position the template fits in the source, computed through an FFT.
+fn fft_cross_correlation(source:&Grid<f64>, template:&Grid<f64>) -> Vec<f64> {
+	let padded_width:usize = source.width.next_power_of_two();
+	let padded_height:usize = source.height.next_power_of_two();
+
+	// Transform both planes, zero-padded to the same power of two size.
+	let transform = |plane:&Grid<f64>| {
+		let mut values:Vec<[f64; 2]> = vec![[0.0, 0.0]; padded_width * padded_height];
+		for (index, value) in plane.data.iter().enumerate() {
+			values[(index / plane.width) * padded_width + index % plane.width] = [*value, 0.0];
+		}
+		fft_2d(&mut values, padded_width, padded_height, false);
+		values
+	};
+	let mut spectrum:Vec<[f64; 2]> = transform(source);
+	let template_spectrum:Vec<[f64; 2]> = transform(template);
+
+	// Multiply the source spectrum with the conjugate template spectrum and transform back.
+	for (source_value, [template_real, template_imaginary]) in spectrum.iter_mut().zip(&template_spectrum) {
+		let [source_real, source_imaginary] = *source_value;
+		*source_value = [source_real * template_real + source_imaginary * template_imaginary, source_imaginary * template_real - source_real * template_imaginary];
+	}
+	fft_2d(&mut spectrum, padded_width, padded_height, true);
+
+	// Positions where the template fits never wrap around the padded size.
+	let map_width:usize = source.width - template.width + 1;
+	let map_height:usize = source.height - template.height + 1;
+	(0..map_width * map_height).map(|index| spectrum[(index / map_width) * padded_width + index % map_width][0]).collect()
+}
+
+/// Transform the rows and then the columns of a grid of complex values of which the width and height are powers of two. The inverse transform is scaled back to the original values.
+fn fft_2d(values:&mut [[f64; 2]], width:usize, height:usize, inverse:bool) {
+	for row in values.chunks_mut(width) {
+		fft(row, inverse);
+	}
+	let mut column:Vec<[f64; 2]> = vec![[0.0, 0.0]; height];
+	for x in 0..width {
+		for y in 0..height {
+			column[y] = values[y * width + x];
+		}
+		fft(&mut column, inverse);
+		for y in 0..height {
+			values[y * width + x] = column[y];
+		}
+	}
+	if inverse {
+		let scale:f64 = 1.0 / (width * height) as f64;
+		for [real, imaginary] in values.iter_mut() {
+			*real *= scale;
+			*imaginary *= scale;
+		}
+	}
+}
+
+/// Transform a list of complex values of which the length is a power of two in-place, using the iterative radix-2 algorithm. The inverse transform is not scaled.
+fn fft(values:&mut [[f64; 2]], inverse:bool) {
+	let length:usize = values.len();
+
+	// Reorder values by bit-reversed index.
+	let mut reversed:usize = 0;
+	for index in 1..length {
+		let mut bit:usize = length >> 1;
+		while reversed & bit != 0 {
+			reversed ^= bit;
+			bit >>= 1;
+		}
+		reversed |= bit;
+		if index < reversed {
+			values.swap(index, reversed);
+		}
+	}
+
+	// Combine ever larger blocks.
+	let mut block_size:usize = 2;
+	while block_size <= length {
+		let angle:f64 = if inverse { 2.0 } else { -2.0 } * std::f64::consts::PI / block_size as f64;
+		for block_start in (0..length).step_by(block_size) {
+			for offset in 0..block_size / 2 {
+				let [twiddle_real, twiddle_imaginary] = [(angle * offset as f64).cos(), (angle * offset as f64).sin()];
+				let [even_real, even_imaginary] = values[block_start + offset];
+				let [odd_real, odd_imaginary] = values[block_start + offset + block_size / 2];
+				let [product_real, product_imaginary] = [odd_real * twiddle_real - odd_imaginary * twiddle_imaginary, odd_real * twiddle_imaginary + odd_imaginary * twiddle_real];
+				values[block_start + offset] = [even_real + product_real, even_imaginary + product_imaginary];
+				values[block_start + offset + block_size / 2] = [even_real - product_real, even_imaginary - product_imaginary];
+			}
+		}
+		block_size <<= 1;
+	}
+}
\ No newline at end of file
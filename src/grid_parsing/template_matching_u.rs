@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod test {
+	use crate::{ Color, Grid, GridError, GridMask, Image, TemplateMatch, TemplateMatchMethod };
+
+
+
+	fn source_grid() -> Grid<u8> {
+		Grid::new((0..48).map(|value:u32| ((value * value * 31 + value * 7) % 97) as u8).collect(), 8, 6)
+	}
+
+
+
+	#[test]
+	fn test_exact_match_all_methods() {
+		let grid:Grid<u8> = source_grid();
+		println!("[grid]\n{grid}\n");
+		let template:Grid<u8> = grid.sub_grid([4, 2, 3, 3]).map(|value| *value);
+		println!("[template]\n{template}\n");
+
+		for method in [TemplateMatchMethod::SquaredDifference, TemplateMatchMethod::AbsoluteDifference, TemplateMatchMethod::NormalizedCrossCorrelation] {
+			let score_map:Grid<f32> = grid.match_template(&template, method).unwrap();
+			assert_eq!([score_map.width, score_map.height], [6, 4]);
+			let best:TemplateMatch = grid.find_template(&template, method).unwrap();
+			assert_eq!(best.position, [4, 2]);
+			assert_eq!(best.score, if method.higher_is_better() { 1.0 } else { 0.0 });
+		}
+	}
+
+	#[test]
+	fn test_score_values() {
+		let grid:Grid<i32> = Grid::new(vec![1, 2, 3, 4, 6, 8], 3, 2);
+		let template:Grid<i32> = Grid::new(vec![2, 4], 1, 2);
+
+		assert_eq!(grid.match_template(&template, TemplateMatchMethod::SquaredDifference).unwrap().data, vec![1.0, 4.0, 17.0]);
+		assert_eq!(grid.match_template(&template, TemplateMatchMethod::AbsoluteDifference).unwrap().data, vec![1.0, 2.0, 5.0]);
+		assert_eq!(grid.match_template(&template, TemplateMatchMethod::NormalizedCrossCorrelation).unwrap().data, vec![1.0, 1.0, 1.0]);
+	}
+
+	#[test]
+	fn test_flat_regions() {
+		let grid:Grid<u8> = Grid::new(vec![
+			10, 10, 200, 200, 10, 10,
+			10, 10, 200, 200, 10, 10
+		], 6, 2);
+		let template:Grid<u8> = Grid::new(vec![10; 4], 2, 2);
+		let full_mask:GridMask = GridMask::new(Grid::new(vec![true; 4], 2, 2));
+
+		assert_eq!(grid.match_template(&template, TemplateMatchMethod::NormalizedCrossCorrelation).unwrap().data, vec![1.0, 0.0, 0.0, 0.0, 1.0]);
+		assert_eq!(grid.match_template_masked(&template, &full_mask, TemplateMatchMethod::NormalizedCrossCorrelation).unwrap().data, vec![1.0, 0.0, 0.0, 0.0, 1.0]);
+		assert_eq!(Grid::new(vec![200u8; 4], 2, 2).match_template(&template, TemplateMatchMethod::NormalizedCrossCorrelation).unwrap().data, vec![0.0]);
+	}
+
+	#[test]
+	fn test_brightness_invariance() {
+		let grid:Grid<f32> = source_grid().map(|value| value as f32);
+		let template:Grid<f32> = grid.sub_grid([1, 1, 4, 3]).map(|value| *value * 0.5 + 40.0);
+
+		let best:TemplateMatch = grid.find_template(&template, TemplateMatchMethod::NormalizedCrossCorrelation).unwrap();
+		assert_eq!(best.position, [1, 1]);
+		assert!((best.score - 1.0).abs() < 1e-5);
+		assert_ne!(grid.find_template(&template, TemplateMatchMethod::SquaredDifference).unwrap().score, 0.0);
+	}
+
+	#[test]
+	fn test_masked_match() {
+		let grid:Grid<u8> = source_grid();
+		let mut template:Grid<u8> = grid.sub_grid([2, 3, 3, 3]).map(|value| *value);
+		template[[1, 1]] = 200;
+		let mask:GridMask = GridMask::new(Grid::new(vec![true, true, true, true, false, true, true, true, true], 3, 3));
+
+		for method in [TemplateMatchMethod::SquaredDifference, TemplateMatchMethod::AbsoluteDifference, TemplateMatchMethod::NormalizedCrossCorrelation] {
+			let best:TemplateMatch = grid.find_template_masked(&template, &mask, method).unwrap();
+			assert_eq!(best.position, [2, 3]);
+			assert_eq!(best.score, if method.higher_is_better() { 1.0 } else { 0.0 });
+		}
+		let unmasked_score_map:Grid<f32> = grid.match_template(&template, TemplateMatchMethod::SquaredDifference).unwrap();
+		let full_mask:GridMask = GridMask::new(Grid::new(vec![true; 9], 3, 3));
+		assert_eq!(grid.match_template_masked(&template, &full_mask, TemplateMatchMethod::SquaredDifference).unwrap(), unmasked_score_map);
+	}
+
+	#[test]
+	fn test_image_match() {
+		let image:Image = source_grid().map(|value| Color(0xFF000000 | (value as u32) << 16 | (97 - value as u32) << 8 | (value as u32 * 3)));
+		let template:Image = image.sub_grid([5, 0, 3, 2]).map(|color| *color);
+
+		assert_eq!(image.find_template(&template, TemplateMatchMethod::NormalizedCrossCorrelation).unwrap().position, [5, 0]);
+		assert_eq!(image.find_template(&template, TemplateMatchMethod::SquaredDifference).unwrap(), TemplateMatch { position: [5, 0], score: 0.0 });
+	}
+
+	#[test]
+	fn test_large_template() {
+		let noise = |width:usize, height:usize| -> Grid<u8> {
+			Grid::new((0..(width * height) as u64).map(|value| (((value * 2654435761) % 4294967296) >> 24) as u8).collect(), width, height)
+		};
+
+		// Large templates are correlated through an FFT, which should score like comparing every pixel.
+		let grid:Grid<u8> = noise(64, 64);
+		let template:Grid<u8> = grid.sub_grid([21, 37, 16, 16]).map(|value| *value);
+		let full_mask:GridMask = GridMask::new(Grid::new(vec![true; 256], 16, 16));
+		for method in [TemplateMatchMethod::SquaredDifference, TemplateMatchMethod::NormalizedCrossCorrelation] {
+			let score_map:Grid<f32> = grid.match_template(&template, method).unwrap();
+			let compared_score_map:Grid<f32> = grid.match_template_masked(&template, &full_mask, method).unwrap();
+			assert!(score_map.iter().zip(compared_score_map.iter()).all(|(score, compared_score)| (score - compared_score).abs() <= 1e-3 * compared_score.abs().max(1.0)));
+			assert_eq!(grid.find_template(&template, method).unwrap().position, [21, 37]);
+		}
+
+		// A search that would take billions of operations comparing every pixel.
+		let grid:Grid<u8> = noise(512, 512);
+		let template:Grid<u8> = grid.sub_grid([300, 170, 64, 64]).map(|value| *value);
+		let best:TemplateMatch = grid.find_template(&template, TemplateMatchMethod::NormalizedCrossCorrelation).unwrap();
+		assert_eq!(best.position, [300, 170]);
+		assert!((best.score - 1.0).abs() < 1e-5);
+		assert_eq!(grid.find_template(&template, TemplateMatchMethod::SquaredDifference).unwrap().position, [300, 170]);
+	}
+
+	#[test]
+	fn test_invalid_sizes() {
+		let grid:Grid<u8> = source_grid();
+
+		assert!(matches!(grid.match_template(&Grid::new(vec![0; 9], 9, 1), TemplateMatchMethod::AbsoluteDifference), Err(GridError::SubGridTooLarge { .. })));
+		assert!(matches!(grid.match_template_masked(&Grid::new(vec![0; 4], 2, 2), &GridMask::new(Grid::new(vec![true; 9], 3, 3)), TemplateMatchMethod::AbsoluteDifference), Err(GridError::SizeMismatch { .. })));
+	}
+}
\ No newline at end of file
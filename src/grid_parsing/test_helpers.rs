@@ -0,0 +1,8 @@
+use crate::Grid;
+
+
+
+/// Create a boolean grid from rows of text, where an 'x' marks a positive pixel.
+pub(crate) fn grid_from_strings(rows:&[&str]) -> Grid<bool> {
+	Grid::new(rows.iter().flat_map(|row| row.chars().map(|character| character == 'x')).collect(), rows[0].len(), rows.len())
+}
\ No newline at end of file
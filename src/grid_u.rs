@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-	use crate::Grid;
+	use crate::{ Grid, GridError };
 
 
 
@@ -33,4 +33,22 @@ mod tests {
 		assert_eq!(grid.data_2d()[7], ['x', 'x', 'x', ' ', ' ', ' ', 'x', 'x', 'x', ' ', ' ', ' ', 'x', 'x', 'x', ' ', ' ', ' ', 'x', 'x', 'x', ' ', ' ', ' ', 'x', 'x', 'x']);
 		assert_eq!(grid.data_2d()[8], ['x', 'x', 'x', ' ', ' ', ' ', 'x', 'x', 'x', ' ', ' ', ' ', 'x', 'x', 'x', ' ', ' ', ' ', 'x', 'x', 'x', ' ', ' ', ' ', 'x', 'x', 'x']);
 	}
+
+	#[test]
+	fn test_try_new() {
+		let grid:Grid<u8> = Grid::try_new(vec![1, 2, 3, 4, 5, 6], 3, 2).unwrap();
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!([grid.width, grid.height], [3, 2]);
+		assert!(matches!(Grid::try_new(vec![1, 2, 3, 4, 5], 3, 2), Err(GridError::DataLengthMismatch { expected: 6, found: 5 })));
+	}
+
+	#[test]
+	fn test_equality_includes_dimensions() {
+		let grid:Grid<u8> = Grid::new(vec![1, 2, 3, 4, 5, 6], 3, 2);
+		let transposed:Grid<u8> = Grid::new(vec![1, 2, 3, 4, 5, 6], 2, 3);
+
+		assert_eq!(grid, grid.clone());
+		assert_ne!(grid, transposed);
+	}
 }
\ No newline at end of file
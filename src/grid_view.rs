@@ -0,0 +1,523 @@
+use std::{ fmt::Display, ops::{ Index, IndexMut } };
+use crate::{ Grid, GridMask };
+
+
+
+pub struct GridView<'a, T> {
+	data:&'a [T],
+	origin:[usize; 2],
+	width:usize,
+	height:usize,
+	stride:usize
+}
+impl<'a, T> GridView<'a, T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new view on data that is laid out in rows of 'stride' values. Bounds are XYWH within that data.
+	pub fn new(data:&'a [T], stride:usize, bounds:[usize; 4]) -> GridView<'a, T> {
+		validate_view_bounds(data.len(), stride, bounds);
+		GridView {
+			data,
+			origin: [bounds[0], bounds[1]],
+			width: bounds[2],
+			height: bounds[3],
+			stride
+		}
+	}
+
+
+
+	/* GETTER METHODS */
+
+	/// Get the XY position of the top-left corner of the view in the parent data.
+	pub fn origin(&self) -> [usize; 2] {
+		self.origin
+	}
+
+	/// Get the width of the view.
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Get the height of the view.
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	/// Get the amount of values in a row of the parent data.
+	pub fn stride(&self) -> usize {
+		self.stride
+	}
+
+	/// Get the amount of values in the view.
+	pub fn len(&self) -> usize {
+		self.width * self.height
+	}
+
+	/// Get wether or not the view has no values.
+	pub fn is_empty(&self) -> bool {
+		self.width == 0 || self.height == 0
+	}
+
+	/// Get a reference to the value at the given position in the view. Returns None if the position falls outside of the view.
+	pub fn get<U>(&self, indexer:U) -> Option<&'a T> where U:GridViewIndexer {
+		let [x, y] = indexer.to_view_xy(self.width);
+		if x < self.width && y < self.height {
+			Some(&self.data[self.parent_index(x, y)])
+		} else {
+			None
+		}
+	}
+
+	/// Create a view on a part of this view. Bounds are XYWH relative to this view and are cropped to fit.
+	pub fn view(&self, bounds:[usize; 4]) -> GridView<'a, T> {
+		let [x, y, width, height] = crop_view_bounds(self.width, self.height, bounds);
+		GridView {
+			data: self.data,
+			origin: [self.origin[0] + x, self.origin[1] + y],
+			width,
+			height,
+			stride: self.stride
+		}
+	}
+
+
+
+	/* INDEXING METHODS */
+
+	/// Convert an X and Y coordinate in the view to an index in the view.
+	pub fn xy_to_index(&self, x:usize, y:usize) -> usize {
+		y * self.width + x
+	}
+
+	/// Convert an index in the view to an X and Y coordinate in the view.
+	pub fn index_to_xy(&self, index:usize) -> [usize; 2] {
+		[index % self.width, index / self.width]
+	}
+
+	/// Wether or not the given index is valid in the view.
+	pub fn index_is_valid(&self, index:usize) -> bool {
+		index < self.len()
+	}
+
+	/// Convert an X and Y coordinate in the view to an index in the parent data.
+	fn parent_index(&self, x:usize, y:usize) -> usize {
+		(self.origin[1] + y) * self.stride + self.origin[0] + x
+	}
+
+
+
+	/* ITERATION METHODS */
+
+	/// Iterate over the rows of the view.
+	pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+		let data:&'a [T] = self.data;
+		let [origin_x, origin_y] = self.origin;
+		let (width, stride) = (self.width, self.stride);
+		(origin_y..origin_y + self.height).map(move |y| &data[y * stride + origin_x..y * stride + origin_x + width])
+	}
+
+	/// Iterate over the values of the view.
+	pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+		self.rows().flatten()
+	}
+
+	/// Iterate over the values of the view with their according X and Y coordinate in the view.
+	pub fn pixel_iterator(&self) -> impl Iterator<Item = (usize, usize, &'a T)> + 'a {
+		self.rows().enumerate().flat_map(|(y, row)| row.iter().enumerate().map(move |(x, value)| (x, y, value)))
+	}
+
+
+
+	/* CONVERSION METHODS */
+
+	/// Convert the view to a new grid of another type.
+	pub fn map_ref<U, V>(&self, conversion_function:V) -> Grid<U> where V:FnMut(&T) -> U {
+		Grid::new(self.iter().map(conversion_function).collect(), self.width, self.height)
+	}
+
+	/// Copy the values in the view to a new grid.
+	pub fn to_grid(&self) -> Grid<T> where T:Clone {
+		Grid::new(self.iter().cloned().collect(), self.width, self.height)
+	}
+}
+impl<T> GridView<'_, T> where T:PartialEq {
+
+	/* SIMILARITY METHODS */
+
+	/// Compare this view to another. Returns 'true' if the similarity reaches the given threshold. Views of different sizes are never similar.
+	pub fn similar_to(&self, other:&GridView<T>, similarity_threshold_factor:f32) -> bool {
+		if (self.width, self.height) != (other.width, other.height) {
+			return false;
+		}
+		let max_mismatches:usize = ((1.0 - similarity_threshold_factor.min(1.0)) * self.len() as f32).round() as usize;
+		self.mismatches_within(other, [0, 0], max_mismatches).is_some()
+	}
+
+	/// Compare this view to another. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. Views of different sizes have a similarity of 0.0, empty views of the same size are a full match.
+	pub fn similarity_to(&self, other:&GridView<T>) -> f32 {
+		if (self.width, self.height) != (other.width, other.height) {
+			return 0.0;
+		}
+		if self.is_empty() {
+			return 1.0;
+		}
+		let matches:usize = self.iter().zip(other.iter()).filter(|(left, right)| left == right).count();
+		matches as f32 / self.len() as f32
+	}
+
+	/// Compare this view to another. Only compare the pixels matching the given mask. Returns the factor of similarity where 0.0 is no similarity and 1.0 is a full match. Views or masks of different sizes have a similarity of 0.0, a mask without positive pixels gives a full match.
+	pub fn similarity_to_masked(&self, other:&GridView<T>, mask:&GridMask) -> f32 {
+		if (self.width, self.height) != (other.width, other.height) || (self.width, self.height) != (mask.width(), mask.height()) {
+			return 0.0;
+		}
+		let comparing_pixel_count:usize = mask.positive_ranges().iter().map(|range| range.end - range.start).sum();
+		if comparing_pixel_count == 0 {
+			return 1.0;
+		}
+		let matches:usize = mask.positive_ranges().iter().flat_map(|range| range.clone()).filter(|index| {
+			let [x, y] = self.index_to_xy(*index);
+			self.data[self.parent_index(x, y)] == other.data[other.parent_index(x, y)]
+		}).count();
+		matches as f32 / comparing_pixel_count as f32
+	}
+
+	/// Find the given sub-grid in the view. Returns the topleft coordinates in the view of the first position where the similarity reaches the given threshold.
+	pub fn find(&self, sub_grid:&Grid<T>, similarity_threshold_factor:f32) -> Option<[usize; 2]> {
+		if sub_grid.width > self.width || sub_grid.height > self.height {
+			return None;
+		}
+		let sub_view:GridView<T> = sub_grid.full_view();
+		let max_mismatches:usize = ((1.0 - similarity_threshold_factor.min(1.0)) * sub_view.len() as f32).round() as usize;
+		for y in 0..self.height - sub_grid.height + 1 {
+			for x in 0..self.width - sub_grid.width + 1 {
+				if sub_view.mismatches_within(self, [x, y], max_mismatches).is_some() {
+					return Some([x, y]);
+				}
+			}
+		}
+		None
+	}
+
+	/// Count the mismatches between self and an equally sized area at the given position in the other view. Returns None as soon as the amount of mismatches exceeds the maximum.
+	fn mismatches_within(&self, other:&GridView<T>, position:[usize; 2], max_mismatches:usize) -> Option<usize> {
+		let mut mismatches:usize = 0;
+		for (y, row) in self.rows().enumerate() {
+			let other_row_start:usize = other.parent_index(position[0], position[1] + y);
+			for (left, right) in row.iter().zip(&other.data[other_row_start..other_row_start + self.width]) {
+				if left != right {
+					mismatches += 1;
+					if mismatches > max_mismatches {
+						return None;
+					}
+				}
+			}
+		}
+		Some(mismatches)
+	}
+}
+impl<T> Clone for GridView<'_, T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+impl<T> Copy for GridView<'_, T> {}
+impl<T, U> Index<U> for GridView<'_, T> where U:GridViewIndexer {
+	type Output = T;
+
+	fn index(&self, indexer:U) -> &Self::Output {
+		let [x, y] = indexer.to_view_xy(self.width);
+		assert!(x < self.width && y < self.height, "Position [{x}, {y}] falls outside of view of {}x{}", self.width, self.height);
+		&self.data[self.parent_index(x, y)]
+	}
+}
+impl<T> Display for GridView<'_, T> where T:ToString {
+	fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.map_ref(|value| value.to_string()))
+	}
+}
+
+
+
+pub struct GridViewMut<'a, T> {
+	data:&'a mut [T],
+	origin:[usize; 2],
+	width:usize,
+	height:usize,
+	stride:usize
+}
+impl<'a, T> GridViewMut<'a, T> {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create a new mutable view on data that is laid out in rows of 'stride' values. Bounds are XYWH within that data.
+	pub fn new(data:&'a mut [T], stride:usize, bounds:[usize; 4]) -> GridViewMut<'a, T> {
+		validate_view_bounds(data.len(), stride, bounds);
+		GridViewMut {
+			data,
+			origin: [bounds[0], bounds[1]],
+			width: bounds[2],
+			height: bounds[3],
+			stride
+		}
+	}
+
+
+
+	/* GETTER METHODS */
+
+	/// Get the XY position of the top-left corner of the view in the parent data.
+	pub fn origin(&self) -> [usize; 2] {
+		self.origin
+	}
+
+	/// Get the width of the view.
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Get the height of the view.
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	/// Get the amount of values in a row of the parent data.
+	pub fn stride(&self) -> usize {
+		self.stride
+	}
+
+	/// Get the amount of values in the view.
+	pub fn len(&self) -> usize {
+		self.width * self.height
+	}
+
+	/// Get wether or not the view has no values.
+	pub fn is_empty(&self) -> bool {
+		self.width == 0 || self.height == 0
+	}
+
+	/// Get a reference to the value at the given position in the view. Returns None if the position falls outside of the view.
+	pub fn get<U>(&self, indexer:U) -> Option<&T> where U:GridViewIndexer {
+		let [x, y] = indexer.to_view_xy(self.width);
+		if x < self.width && y < self.height {
+			Some(&self.data[self.parent_index(x, y)])
+		} else {
+			None
+		}
+	}
+
+	/// Get a mutable reference to the value at the given position in the view. Returns None if the position falls outside of the view.
+	pub fn get_mut<U>(&mut self, indexer:U) -> Option<&mut T> where U:GridViewIndexer {
+		let [x, y] = indexer.to_view_xy(self.width);
+		if x < self.width && y < self.height {
+			let index:usize = self.parent_index(x, y);
+			Some(&mut self.data[index])
+		} else {
+			None
+		}
+	}
+
+	/// Get a read-only view of the same area.
+	pub fn as_view(&self) -> GridView<'_, T> {
+		GridView {
+			data: self.data,
+			origin: self.origin,
+			width: self.width,
+			height: self.height,
+			stride: self.stride
+		}
+	}
+
+	/// Create a mutable view on a part of this view. Bounds are XYWH relative to this view and are cropped to fit.
+	pub fn view_mut(&mut self, bounds:[usize; 4]) -> GridViewMut<'_, T> {
+		let [x, y, width, height] = crop_view_bounds(self.width, self.height, bounds);
+		GridViewMut {
+			data: self.data,
+			origin: [self.origin[0] + x, self.origin[1] + y],
+			width,
+			height,
+			stride: self.stride
+		}
+	}
+
+
+
+	/* INDEXING METHODS */
+
+	/// Convert an X and Y coordinate in the view to an index in the view.
+	pub fn xy_to_index(&self, x:usize, y:usize) -> usize {
+		y * self.width + x
+	}
+
+	/// Convert an index in the view to an X and Y coordinate in the view.
+	pub fn index_to_xy(&self, index:usize) -> [usize; 2] {
+		[index % self.width, index / self.width]
+	}
+
+	/// Wether or not the given index is valid in the view.
+	pub fn index_is_valid(&self, index:usize) -> bool {
+		index < self.len()
+	}
+
+	/// Convert an X and Y coordinate in the view to an index in the parent data.
+	fn parent_index(&self, x:usize, y:usize) -> usize {
+		(self.origin[1] + y) * self.stride + self.origin[0] + x
+	}
+
+
+
+	/* ITERATION METHODS */
+
+	/// Iterate over the rows of the view.
+	pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+		(self.origin[1]..self.origin[1] + self.height).map(|y| &self.data[y * self.stride + self.origin[0]..y * self.stride + self.origin[0] + self.width])
+	}
+
+	/// Iterate over the mutable rows of the view.
+	pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+		let [origin_x, origin_y] = self.origin;
+		let width:usize = self.width;
+		let row_data:&mut [T] = if self.height == 0 { &mut [] } else { &mut self.data[origin_y * self.stride..] };
+		row_data.chunks_mut(self.stride.max(1)).take(self.height).map(move |row| &mut row[origin_x..origin_x + width])
+	}
+
+	/// Iterate over the values of the view.
+	pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+		self.rows().flatten()
+	}
+
+	/// Iterate over the mutable values of the view.
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+		self.rows_mut().flatten()
+	}
+
+	/// Iterate over the mutable values of the view with their according X and Y coordinate in the view.
+	pub fn pixel_iterator_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> + '_ {
+		self.rows_mut().enumerate().flat_map(|(y, row)| row.iter_mut().enumerate().map(move |(x, value)| (x, y, value)))
+	}
+
+
+
+	/* MODIFICATION METHODS */
+
+	/// Append a grid to overwrite part of the view at the given position in the view. Will ignore any pixels out of bounds.
+	pub fn append_at(&mut self, addition:&Grid<T>, offset:[usize; 2]) where T:Clone {
+		let mut target:GridViewMut<T> = self.view_mut([offset[0], offset[1], addition.width, addition.height]);
+		let target_width:usize = target.width;
+		for (target_row, addition_row) in target.rows_mut().zip(addition.data.chunks(addition.width.max(1))) {
+			target_row.clone_from_slice(&addition_row[..target_width]);
+		}
+	}
+
+	/// Set all values in the view to the given value.
+	pub fn fill(&mut self, value:T) where T:Clone {
+		for target in self.iter_mut() {
+			*target = value.clone();
+		}
+	}
+
+
+
+	/* CONVERSION METHODS */
+
+	/// Convert the view to a new grid of another type.
+	pub fn map_ref<U, V>(&self, conversion_function:V) -> Grid<U> where V:FnMut(&T) -> U {
+		Grid::new(self.iter().map(conversion_function).collect(), self.width, self.height)
+	}
+
+	/// Copy the values in the view to a new grid.
+	pub fn to_grid(&self) -> Grid<T> where T:Clone {
+		Grid::new(self.iter().cloned().collect(), self.width, self.height)
+	}
+}
+impl<T, U> Index<U> for GridViewMut<'_, T> where U:GridViewIndexer {
+	type Output = T;
+
+	fn index(&self, indexer:U) -> &Self::Output {
+		self.get(indexer).expect("Position falls outside of view")
+	}
+}
+impl<T, U> IndexMut<U> for GridViewMut<'_, T> where U:GridViewIndexer {
+	fn index_mut(&mut self, indexer:U) -> &mut Self::Output {
+		self.get_mut(indexer).expect("Position falls outside of view")
+	}
+}
+impl<T> Display for GridViewMut<'_, T> where T:ToString {
+	fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.map_ref(|value| value.to_string()))
+	}
+}
+
+
+
+impl<T> Grid<T> {
+
+	/* VIEW METHODS */
+
+	/// Create a borrowed view on part of the grid without copying any values. Bounds are XYWH and are cropped to fit the grid.
+	pub fn view(&self, bounds:[usize; 4]) -> GridView<'_, T> {
+		GridView::new(&self.data, self.width, crop_view_bounds(self.width, self.height, bounds))
+	}
+
+	/// Create a borrowed view on the entire grid.
+	pub fn full_view(&self) -> GridView<'_, T> {
+		self.view([0, 0, self.width, self.height])
+	}
+
+	/// Create a mutable borrowed view on part of the grid without copying any values. Bounds are XYWH and are cropped to fit the grid.
+	pub fn view_mut(&mut self, bounds:[usize; 4]) -> GridViewMut<'_, T> {
+		let bounds:[usize; 4] = crop_view_bounds(self.width, self.height, bounds);
+		GridViewMut::new(&mut self.data, self.width, bounds)
+	}
+
+	/// Create a mutable borrowed view on the entire grid.
+	pub fn full_view_mut(&mut self) -> GridViewMut<'_, T> {
+		self.view_mut([0, 0, self.width, self.height])
+	}
+}
+
+
+
+/// Crop XYWH bounds to fit in an area of the given size.
+fn crop_view_bounds(width:usize, height:usize, bounds:[usize; 4]) -> [usize; 4] {
+	let start_x:usize = bounds[0].min(width);
+	let start_y:usize = bounds[1].min(height);
+	let end_x:usize = (bounds[0].saturating_add(bounds[2])).min(width);
+	let end_y:usize = (bounds[1].saturating_add(bounds[3])).min(height);
+	[start_x, start_y, end_x - start_x, end_y - start_y]
+}
+
+/// Validate that XYWH bounds fit in data of the given length laid out in rows of 'stride' values.
+fn validate_view_bounds(data_len:usize, stride:usize, bounds:[usize; 4]) {
+	assert!(bounds[0] + bounds[2] <= stride, "View bounds {:?} do not fit in rows of {} values", bounds, stride);
+	if bounds[2] > 0 && bounds[3] > 0 {
+		assert!((bounds[1] + bounds[3] - 1) * stride + bounds[0] + bounds[2] <= data_len, "View bounds {:?} do not fit in data of {} values", bounds, data_len);
+	}
+}
+
+
+
+pub trait GridViewIndexer {
+
+	/// Convert the indexer to an X and Y coordinate in a view of the given width.
+	fn to_view_xy(&self, width:usize) -> [usize; 2];
+}
+impl GridViewIndexer for usize {
+	fn to_view_xy(&self, width:usize) -> [usize; 2] {
+		if width == 0 {
+			return [*self, 0];
+		}
+		[self % width, self / width]
+	}
+}
+impl GridViewIndexer for [usize; 2] {
+	fn to_view_xy(&self, _width:usize) -> [usize; 2] {
+		*self
+	}
+}
+impl GridViewIndexer for (usize, usize) {
+	fn to_view_xy(&self, _width:usize) -> [usize; 2] {
+		[self.0, self.1]
+	}
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ Grid, GridMask, GridView, GridViewMut };
+
+
+
+	#[test]
+	fn test_view_size_and_data() {
+		let grid:Grid<i32> = Grid::new((0..20).collect(), 5, 4);
+		println!("[grid]\n{grid}\n");
+		let view:GridView<i32> = grid.view([1, 1, 3, 2]);
+		println!("[view]\n{view}\n");
+
+		assert_eq!(view.origin(), [1, 1]);
+		assert_eq!([view.width(), view.height(), view.stride()], [3, 2, 5]);
+		assert_eq!(view.iter().copied().collect::<Vec<i32>>(), vec![6, 7, 8, 11, 12, 13]);
+		assert_eq!(view.rows().collect::<Vec<&[i32]>>(), vec![&[6, 7, 8][..], &[11, 12, 13][..]]);
+		assert_eq!(view[[2, 1]], 13);
+		assert_eq!(view[(0, 1)], 11);
+		assert_eq!(view[4], 12);
+		assert_eq!(view.get([3, 0]), None);
+		assert_eq!(view.to_grid(), grid.sub_grid([1, 1, 3, 2]).map(|value| *value));
+	}
+
+	#[test]
+	fn test_view_cropping() {
+		let grid:Grid<i32> = Grid::new((0..9).collect(), 3, 3);
+		let view:GridView<i32> = grid.view([1, 2, 10, 10]);
+
+		assert_eq!([view.width(), view.height()], [2, 1]);
+		assert_eq!(view.iter().copied().collect::<Vec<i32>>(), vec![7, 8]);
+		let sub_view:GridView<i32> = grid.full_view().view([1, 0, 2, 2]).view([1, 1, 5, 5]);
+		assert_eq!(sub_view.origin(), [2, 1]);
+		assert_eq!(sub_view.iter().copied().collect::<Vec<i32>>(), vec![5]);
+		assert!(grid.view([5, 5, 1, 1]).is_empty());
+	}
+
+	#[test]
+	fn test_view_map_ref_and_pixel_iterator() {
+		let grid:Grid<i32> = Grid::new((0..9).collect(), 3, 3);
+		let view:GridView<i32> = grid.view([1, 1, 2, 2]);
+		let mapped:Grid<String> = view.map_ref(|value| value.to_string());
+		println!("[mapped]\n{mapped}\n");
+
+		assert_eq!(mapped.data, vec!["4", "5", "7", "8"]);
+		assert_eq!(view.pixel_iterator().collect::<Vec<(usize, usize, &i32)>>(), vec![(0, 0, &4), (1, 0, &5), (0, 1, &7), (1, 1, &8)]);
+	}
+
+	#[test]
+	fn test_view_similarity() {
+		let grid:Grid<i32> = Grid::new(vec![
+			1, 2, 1, 2,
+			3, 4, 3, 5,
+			0, 0, 0, 0
+		], 4, 3);
+		let left:GridView<i32> = grid.view([0, 0, 2, 2]);
+		let right:GridView<i32> = grid.view([2, 0, 2, 2]);
+
+		assert_eq!(left.similarity_to(&right), 0.75);
+		assert!(left.similar_to(&right, 0.75));
+		assert!(!left.similar_to(&right, 0.9));
+		assert_eq!(left.similarity_to(&grid.view([0, 0, 3, 2])), 0.0);
+		assert_eq!(grid.view([1, 1, 0, 2]).similarity_to(&grid.view([3, 0, 0, 2])), 1.0);
+		assert_eq!(grid.view([1, 1, 0, 2]).similarity_to(&grid.view([3, 0, 2, 0])), 0.0);
+		assert!(grid.view([1, 1, 0, 2]).similar_to(&grid.view([3, 0, 0, 2]), 1.0));
+		let mask:GridMask = GridMask::new(Grid::new(vec![true, true, true, false], 2, 2));
+		assert_eq!(left.similarity_to_masked(&right, &mask), 1.0);
+		assert_eq!(left.similarity_to_masked(&grid.view([1, 1, 2, 2]), &GridMask::new(Grid::new(vec![false; 4], 2, 2))), 1.0);
+	}
+
+	#[test]
+	fn test_view_find() {
+		let grid:Grid<i32> = Grid::new(vec![
+			1, 2, 0, 0, 1,
+			0, 0, 1, 2, 2,
+			0, 0, 3, 4, 0,
+			1, 2, 0, 0, 0
+		], 5, 4);
+		let sub_grid:Grid<i32> = Grid::new(vec![1, 2, 3, 4], 2, 2);
+		let view:GridView<i32> = grid.view([1, 1, 4, 3]);
+
+		assert_eq!(view.find(&sub_grid, 1.0), Some([1, 0]));
+		assert_eq!(grid.view([0, 2, 5, 2]).find(&sub_grid, 1.0), None);
+		assert_eq!(grid.view([0, 0, 5, 2]).find(&sub_grid, 0.5), Some([0, 0]));
+	}
+
+	#[test]
+	fn test_view_mut() {
+		let mut grid:Grid<i32> = Grid::new(vec![0; 16], 4, 4);
+		{
+			let mut view:GridViewMut<i32> = grid.view_mut([1, 1, 2, 3]);
+			view.fill(1);
+			view[[1, 2]] = 5;
+			for (x, y, value) in view.pixel_iterator_mut() {
+				if x == 0 && y == 0 {
+					*value = 9;
+				}
+			}
+			assert_eq!(view.as_view().iter().copied().collect::<Vec<i32>>(), vec![9, 1, 1, 1, 1, 5]);
+		}
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(grid.data_2d(), [[0, 0, 0, 0], [0, 9, 1, 0], [0, 1, 1, 0], [0, 1, 5, 0]]);
+	}
+
+	#[test]
+	fn test_view_mut_append_at() {
+		let mut grid:Grid<i32> = Grid::new(vec![0; 16], 4, 4);
+		let addition:Grid<i32> = Grid::new(vec![1, 2, 3, 4, 5, 6], 3, 2);
+		grid.view_mut([1, 1, 3, 3]).append_at(&addition, [1, 0]);
+		println!("[grid]\n{grid}\n");
+
+		assert_eq!(grid.data_2d(), [[0, 0, 0, 0], [0, 0, 1, 2], [0, 0, 4, 5], [0, 0, 0, 0]]);
+		let mut nested:Grid<i32> = Grid::new(vec![0; 16], 4, 4);
+		nested.full_view_mut().view_mut([2, 2, 2, 2]).fill(7);
+		assert_eq!(nested.data_2d(), [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 7, 7], [0, 0, 7, 7]]);
+	}
+}
\ No newline at end of file
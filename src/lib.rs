@@ -1,11 +1,17 @@
 mod grid;
 mod grid_u;
+mod grid_error;
+mod grid_error_u;
+mod grid_view;
+mod grid_view_u;
 mod grid_behavior;
 mod grid_parsing;
 mod storage;
 mod specific_grid_types;
 
 pub use grid::*;
+pub use grid_error::*;
+pub use grid_view::*;
 pub use grid_behavior::*;
 pub use grid_parsing::*;
 pub use storage::*;
@@ -1,8 +1,7 @@
 use super::font_table_parsers::*;
 use bytes_parser::BytesParser;
 use file_ref::FileRef;
-use std::error::Error;
-use crate::Grid;
+use crate::{ Grid, GridError };
 
 
 
@@ -34,7 +33,7 @@ impl Font {
 
 	/// Create a new font reference.
 	/// Can take the raw bytes of the TTF file or a path to the file.
-	pub fn new<Source:TryInto<Font>>(source:Source) -> Result<Font, Box<dyn Error>> where Source::Error:Into<Box<dyn Error>> {
+	pub fn new<Source:TryInto<Font>>(source:Source) -> Result<Font, GridError> where Source::Error:Into<GridError> {
 		match source.try_into() {
 			Ok(font) => Ok(font),
 			Err(error) => Err(error.into())
@@ -42,14 +41,14 @@ impl Font {
 	}
 
 	/// Create a new font reference from raw ttf contents.
-	fn from_contents(ttf_contents:Vec<u8>) -> Result<Font, Box<dyn Error>> {
+	fn from_contents(ttf_contents:Vec<u8>) -> Result<Font, GridError> {
 		const SFNT_VERSION_TT:u32 = 0x00010000;
 		
 		// Parse font header.
 		let mut parser:BytesParser = BytesParser::new(ttf_contents.clone(), true);
 		let sfnt_version:u32 = parser.take()?;
 		if sfnt_version != SFNT_VERSION_TT {
-			return Err("Passed file does not contains TrueType sfnt version.".into());
+			return Err(GridError::Parse("Passed file does not contains TrueType sfnt version.".to_string()));
 		}
 		let table_quantity:u16 = parser.take()?;
 		let _search_range:u16 = parser.take()?;
@@ -137,16 +136,16 @@ impl Font {
 
 		// Return errors for missing data.
 		if head.is_none() {
-			return Err("Could not parse TrueType font Head.".into());
+			return Err(GridError::Parse("Could not parse TrueType font Head.".to_string()));
 		}
 		if hhea.is_none() {
-			return Err("Could not parse TrueType font Hhea.".into());
+			return Err(GridError::Parse("Could not parse TrueType font Hhea.".to_string()));
 		}
 		if cmap.is_none() {
-			return Err("Could not parse TrueType font Cmap.".into());
+			return Err(GridError::Parse("Could not parse TrueType font Cmap.".to_string()));
 		}
 		if glyf.is_none() {
-			return Err("Could not parse TrueType font Glyf.".into());
+			return Err(GridError::Parse("Could not parse TrueType font Glyf.".to_string()));
 		}
 
 		// Return the parsed props.
@@ -386,25 +385,25 @@ impl Font {
 
 
 impl TryInto<Font> for Vec<u8> {
-	type Error = Box<dyn Error>;
+	type Error = GridError;
 	fn try_into(self) -> Result<Font, Self::Error> {
 		Font::from_contents(self)
 	}
 }
 impl TryInto<Font> for FileRef {
-	type Error = Box<dyn Error>;
+	type Error = GridError;
 	fn try_into(self) -> Result<Font, Self::Error> {
 		self.read_bytes()?.try_into()
 	}
 }
 impl TryInto<Font> for String {
-	type Error = Box<dyn Error>;
+	type Error = GridError;
 	fn try_into(self) -> Result<Font, Self::Error> {
 		FileRef::new(&self).try_into()
 	}
 }
 impl TryInto<Font> for &str {
-	type Error = Box<dyn Error>;
+	type Error = GridError;
 	fn try_into(self) -> Result<Font, Self::Error> {
 		FileRef::new(&self).try_into()
 	}
@@ -1,6 +1,5 @@
-use crate::{ FontEncoder, FontEncoderFormat4 };
+use crate::{ FontEncoder, FontEncoderFormat4, GridError };
 use bytes_parser::BytesParser;
-use std::error::Error;
 
 
 
@@ -13,7 +12,7 @@ impl FontCmapProps {
 	/// Try to create a new Cmap properties struct from the given parser.
 	/// Expects the parser to be at the start of the Cmap table.
 	/// Requires the original file contents and address of the table to parse other tables with an offset to this one.
-	pub fn new(table_parser:&mut BytesParser, file_contents:&[u8], table_address:usize) -> Result<FontCmapProps, Box<dyn Error>> {
+	pub fn new(table_parser:&mut BytesParser, file_contents:&[u8], table_address:usize) -> Result<FontCmapProps, GridError> {
 		let mut encoders:Vec<Box<dyn FontEncoder>> = Vec::new();
 		let _version:u16 = table_parser.take()?;
 		let encoding_record_quantity:u16 = table_parser.take()?;
@@ -1,5 +1,5 @@
 use bytes_parser::BytesParser;
-use std::error::Error;
+use crate::GridError;
 
 
 
@@ -71,7 +71,7 @@ impl FontGlyfProps {
 	
 	/// Try to create a new Glyf properties struct from the raw file contents and address.
 	/// Requires the original file contents and address of the table to parse other tables with an offset to this one.
-	pub fn new(file_contents:&[u8], table_address:usize, glyph_offsets:&[usize]) -> Result<FontGlyfProps, Box<dyn Error>> {
+	pub fn new(file_contents:&[u8], table_address:usize, glyph_offsets:&[usize]) -> Result<FontGlyfProps, GridError> {
 		const HALF_I16_MAX_F32:f32 = 16384.0; 
 		
 		let mut contours:Vec<(usize, [i16; 4], Vec<Vec<ContourPoint>>)> = Vec::new();
@@ -1,5 +1,5 @@
 use bytes_parser::BytesParser;
-use std::error::Error;
+use crate::GridError;
 
 
 
@@ -11,7 +11,7 @@ impl FontHeadProps {
 	
 	/// Try to create a new Head properties struct from the given parser.
 	/// Expects the parser to be at the start of the Head table.
-	pub fn new(table_parser:&mut BytesParser) -> Result<FontHeadProps, Box<dyn Error>> {
+	pub fn new(table_parser:&mut BytesParser) -> Result<FontHeadProps, GridError> {
 		table_parser.skip(0x12);
 		let units_per_em:u16 = table_parser.take()?;
 		table_parser.skip(0x1E);
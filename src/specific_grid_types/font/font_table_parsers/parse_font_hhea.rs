@@ -1,5 +1,5 @@
 use bytes_parser::BytesParser;
-use std::error::Error;
+use crate::GridError;
 
 
 
@@ -13,7 +13,7 @@ impl FontHheaProps {
 	
 	/// Try to create a new Hhea properties struct from the given parser.
 	/// Expects the parser to be at the start of the Hhea table.
-	pub fn new(table_parser:&mut BytesParser) -> Result<FontHheaProps, Box<dyn Error>> {
+	pub fn new(table_parser:&mut BytesParser) -> Result<FontHheaProps, GridError> {
 		let _version:u32 = table_parser.take()?;
 		let ascent:i16 = table_parser.take()?;
 		let descent:i16 = table_parser.take()?;
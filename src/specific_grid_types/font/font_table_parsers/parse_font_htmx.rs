@@ -1,5 +1,5 @@
 use bytes_parser::BytesParser;
-use std::error::Error;
+use crate::GridError;
 
 
 
@@ -20,7 +20,7 @@ impl FontHmtxProps {
 	
 	/// Try to create a new Hmtx properties struct from the given parser.
 	/// Expects the parser to be at the start of the Hmtx table.
-	pub fn new(table_parser:&mut BytesParser, glyph_count:u16, metrics_quantity:usize) -> Result<FontHmtxProps, Box<dyn Error>> {
+	pub fn new(table_parser:&mut BytesParser, glyph_count:u16, metrics_quantity:usize) -> Result<FontHmtxProps, GridError> {
 		let mut metrics:Vec<FontHmtxMetric> = Vec::new(); // Advance width, Left side bearing
 		for _metrics_index in 0..metrics_quantity {
 			metrics.push(FontHmtxMetric {
@@ -1,5 +1,5 @@
 use bytes_parser::BytesParser;
-use std::error::Error;
+use crate::GridError;
 
 
 
@@ -10,7 +10,7 @@ impl FontLocaProps {
 	
 	/// Try to create a new Loca properties struct from the given parser.
 	/// Expects the parser to be at the start of the Loca table.
-	pub fn new(table_parser:&mut BytesParser, loca_format:u16, glyph_count:u16) -> Result<FontLocaProps, Box<dyn Error>> {
+	pub fn new(table_parser:&mut BytesParser, loca_format:u16, glyph_count:u16) -> Result<FontLocaProps, GridError> {
 		let length:u16 = glyph_count + 1;
 		let offset_size:usize = if loca_format == 0 { 2 } else { 4 };
 		let glyph_offsets:Vec<usize> = table_parser.take_bytes(length as usize * offset_size)?.chunks(offset_size).map(|offset_bytes |
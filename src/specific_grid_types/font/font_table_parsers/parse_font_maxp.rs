@@ -1,5 +1,5 @@
 use bytes_parser::BytesParser;
-use std::error::Error;
+use crate::GridError;
 
 
 
@@ -10,7 +10,7 @@ impl FontMaxpProps {
 	
 	/// Try to create a new Maxp properties struct from the given parser.
 	/// Expects the parser to be at the start of the Maxp table.
-	pub fn new(table_parser:&mut BytesParser) -> Result<FontMaxpProps, Box<dyn Error>> {
+	pub fn new(table_parser:&mut BytesParser) -> Result<FontMaxpProps, GridError> {
 		table_parser.skip(0x4);
 		let glyph_count:u16 = table_parser.take()?;
 		Ok(FontMaxpProps {
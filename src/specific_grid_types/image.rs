@@ -1,5 +1,5 @@
-use std::{ error::Error, fmt::{ Debug, Display }, ops::{Add, AddAssign} };
-use crate::Grid;
+use std::{ fmt::{ Debug, Display }, ops::{Add, AddAssign} };
+use crate::{ Grid, GridError };
 
 
 
@@ -196,8 +196,8 @@ pub trait ImageConversion {
 	fn file_extension() -> &'static str;
 
 	/// Read an image from a file.
-	fn image_from_file<T:ColorConvertible>(path:&str) -> Result<Grid<T>, Box<dyn Error>>;
+	fn image_from_file<T:ColorConvertible>(path:&str) -> Result<Grid<T>, GridError>;
 
 	/// Write an image to a file.
-	fn image_to_file<T:ColorConvertible>(image:Grid<T>, path:&str) -> Result<(), Box<dyn Error>>;
+	fn image_to_file<T:ColorConvertible>(image:Grid<T>, path:&str) -> Result<(), GridError>;
 }
\ No newline at end of file
@@ -0,0 +1,159 @@
+use crate::{ Color, EdgePolicy, Grid, GridError, Image, Kernel };
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ColorChannel {
+	Alpha,
+	Red,
+	Green,
+	Blue
+}
+impl ColorChannel {
+
+	/// All channels in the order they are stored in a color.
+	pub const ALL:[ColorChannel; 4] = [ColorChannel::Alpha, ColorChannel::Red, ColorChannel::Green, ColorChannel::Blue];
+
+	/// Get the value of this channel in the given color.
+	pub fn value_of(&self, color:&Color) -> u8 {
+		match self {
+			ColorChannel::Alpha => *color.a(),
+			ColorChannel::Red => *color.r(),
+			ColorChannel::Green => *color.g(),
+			ColorChannel::Blue => *color.b()
+		}
+	}
+
+	/// Get an edge policy for this channel from an edge policy for colors.
+	pub fn edge_policy_of(&self, edge_policy:&EdgePolicy<Color>) -> EdgePolicy<u8> {
+		match edge_policy {
+			EdgePolicy::Clamp => EdgePolicy::Clamp,
+			EdgePolicy::Wrap => EdgePolicy::Wrap,
+			EdgePolicy::Mirror => EdgePolicy::Mirror,
+			EdgePolicy::Constant(color) => EdgePolicy::Constant(self.value_of(color))
+		}
+	}
+}
+
+
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ChannelStatistics {
+	pub min:u8,
+	pub max:u8,
+	pub mean:f64,
+	pub variance:f64,
+	pub median:f64
+}
+impl ChannelStatistics {
+
+	/// Calculate the statistics of a channel grid. Only uses the positive cells of the mask if one is given. Returns None if no cells are selected. Returns an error if the mask is not the same size as the channel.
+	fn from_channel(channel:&Grid<u8>, mask:Option<&Grid<bool>>) -> Result<Option<ChannelStatistics>, GridError> {
+		let (Some(min), Some(max), Some(mean), Some(variance), Some(median)) = (match mask {
+			Some(mask) => (channel.min_masked(mask)?, channel.max_masked(mask)?, channel.mean_masked(mask)?, channel.variance_masked(mask)?, channel.median_masked(mask)?),
+			None => (channel.min(), channel.max(), channel.mean(), channel.variance(), channel.median())
+		}) else {
+			return Ok(None);
+		};
+		Ok(Some(ChannelStatistics { min, max, mean, variance, median }))
+	}
+}
+
+
+
+impl Image {
+
+	/* CONSTRUCTOR METHODS */
+
+	/// Create an image from grids of the values of each channel. Channels are in the order alpha, red, green, blue. Returns an error if the channels are not all the same size.
+	pub fn from_channels(channels:[Grid<u8>; 4]) -> Result<Image, GridError> {
+		let [alpha, red, green, blue] = channels;
+		for channel in [&red, &green, &blue] {
+			if [channel.width, channel.height] != [alpha.width, alpha.height] {
+				return Err(GridError::SizeMismatch { expected: [alpha.width, alpha.height], found: [channel.width, channel.height] });
+			}
+		}
+		let data:Vec<Color> = alpha.data.iter().zip(&red.data).zip(&green.data).zip(&blue.data).map(|(((a, r), g), b)| Color(u32::from_be_bytes([*a, *r, *g, *b]))).collect();
+		Grid::try_new(data, alpha.width, alpha.height)
+	}
+
+
+
+	/* CHANNEL METHODS */
+
+	/// Get a grid of the values of a single channel.
+	pub fn channel(&self, channel:ColorChannel) -> Grid<u8> {
+		self.map_ref(|color| channel.value_of(color))
+	}
+
+	/// Get grids of the values of all channels. Channels are in the order alpha, red, green, blue.
+	pub fn channels(&self) -> [Grid<u8>; 4] {
+		ColorChannel::ALL.map(|channel| self.channel(channel))
+	}
+
+
+
+	/* STATISTICS METHODS */
+
+	/// Get the statistics of each channel. Channels are in the order alpha, red, green, blue. Returns None if the image is empty.
+	pub fn channel_statistics(&self) -> Option<[ChannelStatistics; 4]> {
+		self.channel_statistics_in_selection(None).unwrap_or_default()
+	}
+
+	/// Get the statistics of each channel, only using the positive cells of the mask. Channels are in the order alpha, red, green, blue. Returns None if the mask has no positive cells. Returns an error if the mask is not the same size as self.
+	pub fn channel_statistics_masked<U>(&self, mask:&U) -> Result<Option<[ChannelStatistics; 4]>, GridError> where U:AsRef<Grid<bool>> {
+		self.channel_statistics_in_selection(Some(mask.as_ref()))
+	}
+
+	/// Get the statistics of each channel, only using the selected cells if a selection is given.
+	fn channel_statistics_in_selection(&self, selection:Option<&Grid<bool>>) -> Result<Option<[ChannelStatistics; 4]>, GridError> {
+		let [alpha, red, green, blue] = self.channels();
+		let (Some(alpha), Some(red), Some(green), Some(blue)) = (
+			ChannelStatistics::from_channel(&alpha, selection)?,
+			ChannelStatistics::from_channel(&red, selection)?,
+			ChannelStatistics::from_channel(&green, selection)?,
+			ChannelStatistics::from_channel(&blue, selection)?
+		) else {
+			return Ok(None);
+		};
+		Ok(Some([alpha, red, green, blue]))
+	}
+
+
+
+	/* CHANNEL FILTERING METHODS */
+
+	/// Create a new image by applying the given filter to each channel separately. Panics if the filter does not return equally sized grids for all channels.
+	pub fn map_channels<U>(&self, filter:U) -> Image where U:Fn(&Grid<u8>, ColorChannel) -> Grid<u8> {
+		let channels:[Grid<u8>; 4] = ColorChannel::ALL.map(|channel| filter(&self.channel(channel), channel));
+		match Image::from_channels(channels) {
+			Ok(image) => image,
+			Err(error) => panic!("Channel filter did not return equally sized channels: {error}")
+		}
+	}
+
+	/// Convolve each channel of the image with the given kernel.
+	pub fn convolve_channels(&self, kernel:&Kernel, edge_policy:&EdgePolicy<Color>) -> Image {
+		self.map_channels(|grid, channel| grid.convolve(kernel, &channel.edge_policy_of(edge_policy)))
+	}
+
+	/// Create a copy of the image where each channel is averaged within the given radius.
+	pub fn box_blur_channels(&self, radius:usize, edge_policy:&EdgePolicy<Color>) -> Image {
+		self.convolve_channels(&Kernel::box_blur(radius), edge_policy)
+	}
+
+	/// Create a copy of the image where each channel is blurred with a gaussian kernel of the given standard deviation.
+	pub fn gaussian_blur_channels(&self, sigma:f64, edge_policy:&EdgePolicy<Color>) -> Image {
+		self.convolve_channels(&Kernel::gaussian(sigma), edge_policy)
+	}
+
+	/// Create a copy of the image where each channel is sharpened.
+	pub fn sharpen_channels(&self, edge_policy:&EdgePolicy<Color>) -> Image {
+		self.convolve_channels(&Kernel::sharpen(), edge_policy)
+	}
+
+	/// Create a copy of the image where each channel is median filtered within the given radius.
+	pub fn median_filter_channels(&self, radius:usize, edge_policy:&EdgePolicy<Color>) -> Image {
+		self.map_channels(|grid, channel| grid.median_filter(radius, &channel.edge_policy_of(edge_policy)))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+	use crate::{ ChannelStatistics, Color, ColorChannel, EdgePolicy, Grid, GridError, GridMask, Image, Kernel };
+
+
+
+	#[test]
+	fn test_channels() {
+		let image:Image = Grid::new(vec![Color(0xFF102030), Color(0x80405060)], 2, 1);
+		let [alpha, red, green, blue] = image.channels();
+		println!("[red]\n{red}\n");
+
+		assert_eq!(alpha.data, vec![0xFF, 0x80]);
+		assert_eq!(red.data, vec![0x10, 0x40]);
+		assert_eq!(green.data, vec![0x20, 0x50]);
+		assert_eq!(blue.data, vec![0x30, 0x60]);
+		assert_eq!(image.channel(ColorChannel::Green), green);
+	}
+
+	#[test]
+	fn test_channel_statistics() {
+		let image:Image = Grid::new(vec![Color(0xFF000010), Color(0xFF000020), Color(0xFF000060), Color(0x00FF0000)], 2, 2);
+		let statistics:[ChannelStatistics; 4] = image.channel_statistics().unwrap();
+
+		assert_eq!([statistics[0].min, statistics[0].max], [0x00, 0xFF]);
+		assert_eq!(statistics[1].mean, 0xFF as f64 / 4.0);
+		assert_eq!(statistics[3].median, 0x18 as f64);
+		assert_eq!(statistics[3].variance, 1328.0);
+
+		let mask:GridMask = GridMask::new(Grid::new(vec![true, true, true, false], 2, 2));
+		let masked_statistics:[ChannelStatistics; 4] = image.channel_statistics_masked(&mask).unwrap().unwrap();
+		assert_eq!([masked_statistics[0].min, masked_statistics[1].max], [0xFF, 0x00]);
+		assert_eq!(masked_statistics[3].mean, 0x30 as f64);
+		assert_eq!(image.channel_statistics_masked(&GridMask::new(Grid::new(vec![false; 4], 2, 2))).unwrap(), None);
+		assert!(matches!(image.channel_statistics_masked(&GridMask::new(Grid::new(vec![true; 2], 2, 1))), Err(GridError::SizeMismatch { .. })));
+		assert_eq!(Image::new(Vec::new(), 0, 0).channel_statistics(), None);
+	}
+
+	#[test]
+	fn test_from_channels() {
+		let image:Image = Grid::new(vec![Color(0xFF102030), Color(0x80405060)], 2, 1);
+
+		assert_eq!(Image::from_channels(image.channels()).unwrap(), image);
+		let [alpha, red, green, _] = image.channels();
+		assert!(matches!(Image::from_channels([alpha, red, green, Grid::new(vec![0], 1, 1)]), Err(GridError::SizeMismatch { .. })));
+	}
+
+	#[test]
+	fn test_channel_filtering() {
+		let image:Image = Grid::new(vec![Color(0xFF000000), Color(0xFF3060FF), Color(0xFF000000)], 3, 1);
+		let blurred:Image = image.box_blur_channels(1, &EdgePolicy::Clamp);
+		println!("[blurred]\n{blurred}\n");
+
+		assert_eq!(blurred.data, vec![Color(0xFF102055), Color(0xFF102055), Color(0xFF102055)]);
+		assert_eq!(image.convolve_channels(&Kernel::new(Grid::new(vec![1.0], 1, 1)), &EdgePolicy::Clamp), image);
+		assert_eq!(image.median_filter_channels(1, &EdgePolicy::Clamp).data, vec![Color(0xFF000000); 3]);
+		assert_eq!(image.map_channels(|grid, channel| if channel == ColorChannel::Red { grid.map_ref(|_| 0xAA) } else { grid.clone() }).data[0], Color(0xFFAA0000));
+	}
+}
\ No newline at end of file
@@ -1,6 +1,9 @@
 mod image;
 mod image_u;
+mod image_channels;
+mod image_channels_u;
 mod font;
 
 pub use image::*;
+pub use image_channels::*;
 pub use font::*;
\ No newline at end of file
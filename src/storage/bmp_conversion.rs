@@ -1,5 +1,5 @@
-use crate::{ Color, ColorConvertible, Grid, ImageConversion };
-use std::{ error::Error, fs, path::Path };
+use crate::{ Color, ColorConvertible, Grid, GridError, ImageConversion };
+use std::{ fs, path::Path };
 use bytes_parser::BytesParser;
 
 
@@ -14,26 +14,26 @@ const BMP_INFO_HEADER_SIZE:u32 = 40;
 impl<T> Grid<T> where T:ColorConvertible {
 
 	/// Read a grid from a BMP file.
-	pub fn from_bmp(file_path:&str) -> Result<Grid<T>, Box<dyn Error>> {
+	pub fn from_bmp(file_path:&str) -> Result<Grid<T>, GridError> {
 		if !Path::new(file_path).exists() {
-			return Err("File does not exist.".into());
+			return Err(GridError::FileNotFound(file_path.to_string()));
 		}
 		Grid::from_bmp_bytes(fs::read(file_path)?)
 	}
 
 	/// Store the grid as a BMP file.
-	pub fn to_bmp(&self, file_path:&str) -> Result<(), Box<dyn Error>> {
+	pub fn to_bmp(&self, file_path:&str) -> Result<(), GridError> {
 		fs::write(file_path, self.to_bmp_bytes())?;
 		Ok(())
 	}
 
 	/// Read a grid from a BMP bytes list.
-	pub(crate) fn from_bmp_bytes(bytes:Vec<u8>) -> Result<Grid<T>, Box<dyn Error>> {
+	pub(crate) fn from_bmp_bytes(bytes:Vec<u8>) -> Result<Grid<T>, GridError> {
 		let mut parser:BytesParser = BytesParser::new(bytes, false);
 
 		// Parse file header.
 		if parser.take::<[u8; 2]>()? != BMP_FILE_SIGNATURE {
-			return Err("File does not include BMP file header signature.".into());
+			return Err(GridError::Parse("File does not include BMP file header signature.".to_string()));
 		}
 		let _full_file_bytes_size:u32 = parser.take()?;
 		let _reserved_1:u16 = parser.take()?;
@@ -42,7 +42,7 @@ impl<T> Grid<T> where T:ColorConvertible {
 
 		// Parse BMP info header.
 		if parser.take::<u32>()? != BMP_INFO_HEADER_SIZE {
-			return Err("File contains incorrect BMP info header size.".into());
+			return Err(GridError::Parse("File contains incorrect BMP info header size.".to_string()));
 		}
 		let width:u32 = parser.take()?;
 		let height:i32 = parser.take()?;
@@ -61,7 +61,7 @@ impl<T> Grid<T> where T:ColorConvertible {
 
 		// Parse color data.
 		if parser.cursor() as u32 != pixel_data_offset {
-			return Err("Image color data was not at expected location.".into());
+			return Err(GridError::Parse("Image color data was not at expected location.".to_string()));
 		}
 		let mut colors:Vec<Vec<Color>> = Vec::new();
 		match bits_per_pixel {
@@ -87,7 +87,7 @@ impl<T> Grid<T> where T:ColorConvertible {
 					parser.skip(row_padding as usize);
 				}
 			}
-			_ => return Err(format!("Unexpected bits per pixel: {bits_per_pixel}.").into())
+			_ => return Err(GridError::Parse(format!("Unexpected bits per pixel: {bits_per_pixel}.")))
 		};
 		if !top_down {
 			colors.reverse();
@@ -155,12 +155,12 @@ impl ImageConversion for BmpConversion {
 	}
 
 	/// Read an image from a file.
-	fn image_from_file<T:ColorConvertible>(path:&str) -> Result<Grid<T>, Box<dyn Error>> {
+	fn image_from_file<T:ColorConvertible>(path:&str) -> Result<Grid<T>, GridError> {
 		Grid::from_bmp(path)
 	}
 
 	/// Write an image to a file.
-	fn image_to_file<T:ColorConvertible>(image:Grid<T>, path:&str) -> Result<(), Box<dyn Error>> {
+	fn image_to_file<T:ColorConvertible>(image:Grid<T>, path:&str) -> Result<(), GridError> {
 		image.to_bmp(path)
 	}
 }
\ No newline at end of file
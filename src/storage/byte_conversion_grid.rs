@@ -1,5 +1,4 @@
-use crate::{ Grid, storage::GridByteConvertible };
-use std::error::Error;
+use crate::{ Grid, GridError, storage::GridByteConvertible };
 
 
 
@@ -23,18 +22,18 @@ impl<T> Grid<T> where T:GridByteConvertible {
 	}
 
 	/// Try to create a grid from bytes.
-	pub fn from_bytes(bytes:&[u8]) -> Result<Self, Box<dyn Error>> {
+	pub fn from_bytes(bytes:&[u8]) -> Result<Self, GridError> {
 
 		// Validate initial byte count.
 		if bytes.len() < MIN_BYTES {
-			return Err(format!("Error creating grid from bytes. Grid from bytes requires at least {} bytes of arguments data. {} bytes provided.", MIN_BYTES, bytes.len()).into());
+			return Err(GridError::Parse(format!("Grid from bytes requires at least {} bytes of arguments data. {} bytes provided.", MIN_BYTES, bytes.len())));
 		}
 
 		// Get args from leading bytes.
 		let width:Option<u32> = StorageArgType::from_bytes(&bytes[0..STORAGE_ARG_SIZE]);
 		let height:Option<u32> = StorageArgType::from_bytes(&bytes[STORAGE_ARG_SIZE..STORAGE_ARG_SIZE * 2]);
 		if width.is_none() || height.is_none() {
-			return Err(format!("Error creating grid from bytes. Could not get grid {} from the first bytes.", if width.is_none() { "width" } else { "height" }).into());
+			return Err(GridError::Parse(format!("Could not get grid {} from the first bytes.", if width.is_none() { "width" } else { "height" })));
 		}
 		let width:usize = width.unwrap() as usize;
 		let height:usize = height.unwrap() as usize;
@@ -42,10 +41,10 @@ impl<T> Grid<T> where T:GridByteConvertible {
 		// Fetch grid data.
 		let data:Option<Vec<T>> = Vec::from_bytes(&bytes[MIN_BYTES..]);
 		if data.is_none() {
-			return Err("Error creating grid from bytes. Could not get data from provided bytes.".into());
+			return Err(GridError::Parse("Could not get grid data from provided bytes.".to_string()));
 		}
 
 		// Create and return grid.
-		Ok(Grid::new(data.unwrap(), width, height))
+		Grid::try_new(data.unwrap(), width, height)
 	}
 }
\ No newline at end of file